@@ -1,9 +1,14 @@
 use super::tree::parse_accessibility_tree;
 use super::tree::parse_accessibility_tree_bounded;
+use crate::engine::rules::utils::contrast::TextContrast;
+use crate::engine::styles::layout::layout_bounds;
+use crate::AuditOptions;
 use accessibility_scraper::ElementRef;
 use accessibility_scraper::Html;
 use accessibility_tree::style::StyleSet;
 use markup5ever::local_name;
+use std::collections::HashMap;
+use std::sync::OnceLock;
 use taffy::TaffyTree;
 
 /// The configuration for auditing
@@ -17,6 +22,12 @@ pub struct Auditor<'a> {
     pub author: StyleSet,
     /// language to get results in
     pub locale: &'a str,
+    /// options to tune the rules
+    pub options: AuditOptions,
+    /// the layout of the elements when the bounding boxes are computed
+    pub bounds: HashMap<ego_tree::NodeId, taffy::Layout>,
+    /// the contrast of the text elements measured once for the contrast rules
+    pub text_contrast: OnceLock<Vec<(ElementRef<'a>, TextContrast)>>,
 }
 
 impl<'a> Auditor<'a> {
@@ -26,6 +37,17 @@ impl<'a> Auditor<'a> {
         css_rules: &str,
         bounds: bool,
         locale: &'a str,
+    ) -> (Auditor<'a>, Option<TaffyTree>) {
+        Auditor::with_options(document, css_rules, bounds, locale, AuditOptions::default())
+    }
+
+    /// Create a new auditor with options to tune the rules
+    pub fn with_options(
+        document: &'a Html,
+        css_rules: &str,
+        bounds: bool,
+        locale: &'a str,
+        options: AuditOptions,
     ) -> (Auditor<'a>, Option<TaffyTree>) {
         // TODO: make stylesheet building optional and only on first requirement
         let author = {
@@ -38,12 +60,13 @@ impl<'a> Auditor<'a> {
                 let mut s = document.select(&selector);
 
                 while let Some(node) = s.next() {
+                    // https://html.spec.whatwg.org/multipage/semantics.html#update-a-style-block
                     if let Some(type_attr) = node.attr(&local_name!("type")) {
-                        if !type_attr.eq_ignore_ascii_case("text/css") {
+                        if !type_attr.is_empty() && !type_attr.eq_ignore_ascii_case("text/css") {
                             continue;
                         }
-                        author.add_stylesheet(&node.inner_html())
                     }
+                    author.add_stylesheet(&node.inner_html())
                 }
             }
             author.finish()
@@ -55,12 +78,20 @@ impl<'a> Auditor<'a> {
             parse_accessibility_tree(&document, &author)
         };
 
+        let bounds = match taffy {
+            Some(ref taffy) => layout_bounds(&tree, taffy),
+            _ => Default::default(),
+        };
+
         (
             Auditor {
                 document,
                 tree,
                 author,
                 locale,
+                options,
+                bounds,
+                text_contrast: OnceLock::new(),
             },
             taffy,
         )
@@ -1,16 +1,11 @@
-use crate::engine::styles::layout::leaf;
+use crate::engine::styles::layout::push_leaf;
 use accessibility_scraper::ElementRef;
 use accessibility_scraper::Html;
 use accessibility_tree::style::StyleSet;
 use std::collections::BTreeMap;
-use std::collections::HashSet;
+use std::collections::HashMap;
 use taffy::prelude::*;
 
-lazy_static! {
-    static ref NODE_IGNORE: HashSet<&'static str> =
-        HashSet::from(["meta", "style", "link", "script", "head", "html", "body"]);
-}
-
 /// try to fix all possible issues using a spec against the tree.
 pub fn parse_accessibility_tree<'a, 'b, 'c>(
     document: &'a Html,
@@ -57,45 +52,26 @@ pub fn parse_accessibility_tree_bounded<'a, 'b, 'c>(
             [(Default::default(), Default::default())]
         });
     let mut layout_leafs: Vec<NodeId> = vec![];
+    let mut layout_nodes: HashMap<ego_tree::NodeId, NodeId> = HashMap::new();
+    let body = document
+        .tree
+        .nodes()
+        .filter_map(ElementRef::wrap)
+        .find(|element| element.value().name() == "body");
 
-    // push taffy layout in order from elements
-    for node in document.tree.nodes() {
-        match ElementRef::wrap(node) {
-            Some(element) => {
-                let name = element.value().name();
-                let layout_leaf = {
-                    if NODE_IGNORE.contains(name) {
-                        taffy.new_leaf(Default::default()).unwrap()
-                    } else {
-                        leaf(&element, &author, document, &mut taffy)
-                    }
-                };
-                accessibility_tree
-                    .entry(name)
-                    .and_modify(|n| n.push((element, Some(layout_leaf))))
-                    .or_insert(Vec::from([(element, Some(layout_leaf))]));
-            }
-            _ => (),
-        };
-    }
-
-    match accessibility_tree.get("body") {
-        Some(node) => {
-            for child in node[0].0.children() {
-                match ElementRef::wrap(child) {
-                    Some(element) => {
-                        if !NODE_IGNORE.contains(element.value().name()) {
-                            let leaf = leaf(&element, &author, document, &mut taffy);
-
-                            layout_leafs.push(leaf)
-                        }
-                    }
-                    _ => (),
-                }
-            }
+    // push taffy layout in order from the body elements
+    if let Some(body) = body {
+        for child in body.children() {
+            push_leaf(
+                &child,
+                author,
+                document,
+                &mut taffy,
+                &mut layout_leafs,
+                &mut layout_nodes,
+            );
         }
-        _ => (),
-    };
+    }
 
     let root_node = taffy
         .new_with_children(
@@ -114,5 +90,24 @@ pub fn parse_accessibility_tree_bounded<'a, 'b, 'c>(
 
     taffy.compute_layout(root_node, Size::MAX_CONTENT).unwrap();
 
+    if let Some(body) = body {
+        layout_nodes.insert(body.id(), root_node);
+    }
+
+    for node in document.tree.nodes() {
+        match ElementRef::wrap(node) {
+            Some(element) => {
+                let name = element.value().name();
+                let layout_leaf = layout_nodes.get(&element.id()).copied();
+
+                accessibility_tree
+                    .entry(name)
+                    .and_modify(|n| n.push((element, layout_leaf)))
+                    .or_insert(Vec::from([(element, layout_leaf)]));
+            }
+            _ => (),
+        };
+    }
+
     (accessibility_tree, Some(taffy))
 }
@@ -5,10 +5,14 @@ use strum_macros::IntoStaticStr;
 pub enum Techniques {
     /// <https://www.w3.org/TR/WCAG20-TECHS/H2>
     H2,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/H4>
+    H4,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H24>
     H24,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H25>
     H25,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/H28>
+    H28,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H32.html>
     H32,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H30>
@@ -23,10 +27,14 @@ pub enum Techniques {
     H42,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H44>
     H44,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/H49>
+    H49,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H53>
     H53,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H57>
     H57,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/H58>
+    H58,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H63>
     H63,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H64>
@@ -35,20 +43,100 @@ pub enum Techniques {
     H67,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H71>
     H71,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/H77>
+    H77,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/H78>
+    H78,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/H79>
+    H79,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/H80>
+    H80,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/H81>
+    H81,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H91>
     H91,
     /// <https://www.w3.org/TR/WCAG20-TECHS/H93>
     H93,
+    /// <https://www.w3.org/WAI/WCAG21/Techniques/html/H95>
+    H95,
+    /// <https://www.w3.org/WAI/WCAG21/Techniques/html/H96>
+    H96,
+    /// <https://www.w3.org/WAI/WCAG21/Techniques/html/H98>
+    H98,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/F16>
+    F16,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/F23>
+    F23,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/F25>
+    F25,
     /// <https://www.w3.org/TR/WCAG20-TECHS/F40>
     F40,
     /// <https://www.w3.org/TR/WCAG20-TECHS/F41>
     F41,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/F42>
+    F42,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/F44>
+    F44,
     /// <https://www.w3.org/TR/WCAG20-TECHS/F47>
     F47,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/F54>
+    F54,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/F73>
+    F73,
     /// <https://www.w3.org/TR/WCAG20-TECHS/F77>
     F77,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/F78>
+    F78,
+    /// <https://www.w3.org/WAI/WCAG21/Techniques/failures/F93>
+    F93,
+    /// <https://www.w3.org/WAI/WCAG21/Techniques/css/C39>
+    C39,
+    /// <https://www.w3.org/WAI/WCAG22/Techniques/css/C42>
+    C42,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G8>
+    G8,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G17>
+    G17,
     /// <https://www.w3.org/TR/WCAG20-TECHS/G18>
     G18,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G69>
+    G69,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G78>
+    G78,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G87>
+    G87,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G93>
+    G93,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G94>
+    G94,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G96>
+    G96,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G102>
+    G102,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G142>
+    G142,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G153>
+    G153,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G158>
+    G158,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G159>
+    G159,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G166>
+    G166,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/G173>
+    G173,
+    /// <https://www.w3.org/WAI/WCAG21/Techniques/general/G183>
+    G183,
+    /// <https://www.w3.org/WAI/WCAG21/Techniques/general/G195>
+    G195,
+    /// <https://www.w3.org/WAI/WCAG21/Techniques/general/G209>
+    G209,
+    /// <https://www.w3.org/TR/WCAG20-TECHS/SCR20>
+    SCR20,
+    /// <https://www.w3.org/WAI/WCAG21/Techniques/aria/ARIA6>
+    ARIA6,
+    /// <https://www.w3.org/WAI/WCAG21/Techniques/aria/ARIA10>
+    ARIA10,
 }
 
 impl Techniques {
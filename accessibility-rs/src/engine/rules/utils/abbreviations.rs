@@ -0,0 +1,209 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::is_hidden_subtree;
+use crate::engine::rules::utils::links::content_locale;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::wcag_base::Guideline;
+use crate::i18n::locales::{get_message_i18n_str_raw, get_phrases_i18n};
+use crate::Auditor;
+use accessibility_scraper::ElementRef;
+use std::collections::HashSet;
+
+/// the elements with code or text that is not read as prose
+const CODE_ELEMENTS: [&str; 9] = [
+    "script", "style", "noscript", "template", "code", "pre", "kbd", "samp", "var",
+];
+/// the longest all caps word treated as an acronym
+const MAX_ACRONYM_LENGTH: usize = 10;
+
+/// the word is written in capitals such as WCAG or HTML5
+fn is_acronym(word: &str) -> bool {
+    let uppercase = word.chars().filter(char::is_ascii_uppercase).count();
+
+    (2..=MAX_ACRONYM_LENGTH).contains(&word.len())
+        && word.starts_with(|c: char| c.is_ascii_uppercase())
+        && word
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        && uppercase >= 2
+        // roman numerals such as IV are numbers
+        && !word.chars().all(|c| "IVXLCDM".contains(c))
+}
+
+/// the word is letters separated by periods such as e.g. or U.S.A.
+fn is_dotted(word: &str) -> bool {
+    let segments = word.strip_suffix('.').unwrap_or(word).split('.');
+    let mut count = 0;
+
+    for segment in segments {
+        if segment.is_empty() || segment.len() > 3 || !segment.chars().all(char::is_alphabetic) {
+            return false;
+        }
+        count += 1;
+    }
+
+    count >= 2
+}
+
+/// the abbreviation written by the word of the text, without the surrounding punctuation or a plural s
+pub fn abbreviation(word: &str) -> Option<&str> {
+    let word = word.trim_matches(|c: char| !c.is_alphanumeric() && c != '.');
+
+    if is_dotted(word) {
+        return Some(word);
+    }
+
+    let word = word.trim_matches('.');
+
+    if is_acronym(word) {
+        Some(word)
+    } else {
+        word.strip_suffix('s').filter(|w| is_acronym(w))
+    }
+}
+
+/// the text is written in capitals for emphasis such as a shouted heading
+fn is_uppercase_text(text: &str) -> bool {
+    let words = text
+        .split_whitespace()
+        .filter(|w| w.chars().filter(|c| c.is_alphabetic()).count() >= 2)
+        .collect::<Vec<_>>();
+    let uppercase = words
+        .iter()
+        .filter(|w| !w.chars().any(char::is_lowercase))
+        .count();
+
+    words.len() >= 3 && uppercase * 2 > words.len()
+}
+
+/// the term compared across the expansions and the glossary
+fn term_key(term: &str) -> String {
+    term.trim_matches('.').to_lowercase()
+}
+
+/// how the text of an element is read
+enum TextContext {
+    /// the text is not prose
+    Skipped,
+    /// the text is explained by an abbr or dfn element or a glossary link
+    Explained,
+    /// the text is prose
+    Prose,
+}
+
+/// the context of the text of the element from its ancestors
+fn text_context(ele: &ElementRef<'_>, phrases: &[String]) -> TextContext {
+    let mut context = TextContext::Prose;
+
+    for e in std::iter::once(*ele).chain(ele.ancestors().filter_map(ElementRef::wrap)) {
+        let name = e.value().name();
+
+        if CODE_ELEMENTS.contains(&name) {
+            return TextContext::Skipped;
+        }
+
+        let explained = match name {
+            "abbr" | "acronym" => !e.attr("title").unwrap_or_default().trim().is_empty(),
+            "a" => e.attr("href").is_some_and(|href| {
+                let href = href.to_lowercase();
+                phrases.iter().any(|p| href.contains(p))
+            }),
+            "dfn" => true,
+            _ => false,
+        };
+
+        if explained {
+            context = TextContext::Explained;
+        }
+    }
+
+    if is_hidden_subtree(ele) {
+        TextContext::Skipped
+    } else {
+        context
+    }
+}
+
+/// validate the abbreviations of the text are expanded on the page or in the glossary
+pub fn validate_abbreviations(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Validation {
+    let locale = content_locale(auditor);
+    let phrases = get_phrases_i18n("glossary", &locale);
+    // abbreviations such as e.g. that are read without an expansion
+    let common = get_phrases_i18n("common_abbreviation", &locale)
+        .iter()
+        .map(|term| term_key(term))
+        .collect::<HashSet<_>>();
+    let mut expanded = auditor
+        .options
+        .glossary
+        .iter()
+        .map(|term| term_key(term))
+        .collect::<HashSet<_>>();
+    let mut occurrences = Vec::new();
+
+    for node in nodes {
+        for child in node.0.descendants() {
+            let text = match child.value().as_text() {
+                Some(text) if !is_uppercase_text(text) => text,
+                _ => continue,
+            };
+            let parent = match child.parent().and_then(ElementRef::wrap) {
+                Some(parent) => parent,
+                _ => continue,
+            };
+            let words = text.split_whitespace().collect::<Vec<_>>();
+            let mut context = None;
+
+            for (i, word) in words.iter().enumerate() {
+                let term = match abbreviation(word) {
+                    Some(term) if !common.contains(&term_key(term)) => term,
+                    _ => continue,
+                };
+                match context.get_or_insert_with(|| text_context(&parent, &phrases)) {
+                    TextContext::Skipped => break,
+                    TextContext::Explained => {
+                        expanded.insert(term_key(term));
+                    }
+                    TextContext::Prose => {
+                        // the expansion in parentheses before or after the abbreviation
+                        let parenthetical = (word.starts_with('(') && word.contains(')'))
+                            || words.get(i + 1).is_some_and(|w| w.starts_with('('));
+
+                        if parenthetical {
+                            expanded.insert(term_key(term));
+                        } else {
+                            occurrences.push((term.to_string(), parent));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut terms: Vec<&str> = Vec::new();
+    let mut parents: Vec<ElementRef<'_>> = Vec::new();
+
+    for (term, parent) in &occurrences {
+        if !expanded.contains(&term_key(term)) {
+            if !terms.contains(&term.as_str()) {
+                terms.push(term);
+            }
+            if !parents.iter().any(|p| p.id() == parent.id()) {
+                parents.push(*parent);
+            }
+        }
+    }
+
+    let elements = parents.iter().map(get_unique_selector).collect::<Vec<_>>();
+
+    let message = if terms.is_empty() {
+        Default::default()
+    } else {
+        t!(
+            &get_message_i18n_str_raw(&Guideline::Readable, "G102,H28", "4", "Unexpanded"),
+            locale = auditor.locale,
+            abbreviations = terms.join(", ")
+        )
+    };
+
+    Validation::new(terms.is_empty(), "Unexpanded", elements, message)
+}
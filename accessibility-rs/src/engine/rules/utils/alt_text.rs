@@ -0,0 +1,220 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::{descendant_elements, is_hidden_subtree};
+use crate::engine::rules::utils::links::{content_locale, normalize_link_text};
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::i18n::locales::get_phrases_i18n;
+use crate::Auditor;
+use accessibility_scraper::{ElementRef, Node};
+
+/// the characters of an alt text past which it no longer reads as a short text alternative
+pub const MAX_ALT_LENGTH: usize = 150;
+/// the file extensions of images
+const IMAGE_EXTENSIONS: [&str; 9] = [
+    ".apng", ".avif", ".bmp", ".gif", ".jpeg", ".jpg", ".png", ".svg", ".webp",
+];
+/// the prefixes of the names cameras and phones give the pictures they take
+const CAMERA_PREFIXES: [&str; 8] = [
+    "img",
+    "dsc",
+    "dscn",
+    "dscf",
+    "pxl",
+    "dcim",
+    "gopr",
+    "screenshot",
+];
+/// the url prefixes of an address used as the alt text
+const URL_PREFIXES: [&str; 6] = ["http://", "https://", "www.", "data:", "//", "/"];
+/// the file names of the transparent images used for spacing
+const SPACER_NAMES: [&str; 9] = [
+    "spacer",
+    "blank",
+    "pixel",
+    "transparent",
+    "clear",
+    "shim",
+    "trans",
+    "dot",
+    "1x1",
+];
+
+/// the alt text is a file name such as IMG_1234.jpg or DSC01234
+pub fn is_file_name_alt(alt: &str) -> bool {
+    let alt = alt.trim().to_ascii_lowercase();
+
+    if alt.is_empty() || alt.contains(char::is_whitespace) || is_url_alt(&alt) {
+        return false;
+    }
+
+    if IMAGE_EXTENSIONS
+        .iter()
+        .any(|ext| alt.len() > ext.len() && alt.ends_with(ext))
+    {
+        return true;
+    }
+
+    CAMERA_PREFIXES.iter().any(|prefix| {
+        alt.strip_prefix(prefix).is_some_and(|rest| {
+            rest.chars().filter(char::is_ascii_digit).count() >= 3
+                && rest
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || c == '_' || c == '-')
+        })
+    })
+}
+
+/// the alt text is the address of the image or a page
+pub fn is_url_alt(alt: &str) -> bool {
+    let alt = alt.trim().to_ascii_lowercase();
+
+    !alt.contains(char::is_whitespace) && URL_PREFIXES.iter().any(|p| alt.starts_with(p))
+}
+
+/// the alt text is a generic word such as image or starts with a phrase such as picture of
+pub fn is_placeholder_alt(alt: &str, placeholders: &[String], prefixes: &[String]) -> bool {
+    let alt = normalize_link_text(alt);
+
+    !alt.is_empty()
+        && (placeholders.contains(&alt)
+            || prefixes.iter().any(|p| {
+                alt.strip_prefix(p.as_str()).is_some_and(|rest| {
+                    // the prefix ends at a word boundary or a script written without spaces
+                    rest.starts_with(|c: char| !c.is_alphanumeric() || c >= '\u{2E80}')
+                })
+            }))
+}
+
+/// the image is a transparent spacer or a single pixel used for the layout
+pub fn is_spacer_image(ele: &ElementRef<'_>) -> bool {
+    let dimension = |attr| {
+        ele.attr(attr)
+            .and_then(|v| v.trim().trim_end_matches("px").parse::<f32>().ok())
+    };
+    let single_pixel = matches!((dimension("width"), dimension("height")), (Some(w), Some(h)) if w <= 1.0 && h <= 1.0);
+
+    let src = ele.attr("src").unwrap_or_default().to_ascii_lowercase();
+    let file = src
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    let stem = file.rsplit_once('.').map_or(file, |(stem, _)| stem);
+
+    single_pixel || SPACER_NAMES.contains(&stem)
+}
+
+/// the text of the caption of the figure containing the image
+fn figure_caption(ele: &ElementRef<'_>) -> Option<String> {
+    let figure = ele
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .find(|e| e.value().name() == "figure")?;
+
+    descendant_elements(&figure)
+        .find(|e| e.value().name() == "figcaption")
+        .map(|caption| normalize_link_text(&caption.text().collect::<String>()))
+}
+
+/// validate the alt text of the images describes the image rather than its source or a placeholder
+pub fn validate_alt_text(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Vec<Validation> {
+    let locale = content_locale(auditor);
+    let placeholders = get_phrases_i18n("placeholder_alt", &locale);
+    let prefixes = get_phrases_i18n("redundant_alt", &locale);
+    let mut validations: Vec<(&'static str, Vec<String>)> = [
+        "Image.FileName",
+        "Image.Url",
+        "Image.Placeholder",
+        "Image.Caption",
+        "Image.TooLong",
+        "Image.Spacer",
+    ]
+    .into_iter()
+    .map(|id| (id, Vec::new()))
+    .collect();
+
+    for node in nodes {
+        let ele = node.0;
+        let alt = match ele.attr("alt") {
+            Some(alt) if !alt.trim().is_empty() => alt,
+            _ => continue,
+        };
+
+        if is_hidden_subtree(&ele) {
+            continue;
+        }
+
+        let normalized = normalize_link_text(alt);
+        let invalid = [
+            is_file_name_alt(alt),
+            is_url_alt(alt),
+            is_placeholder_alt(alt, &placeholders, &prefixes),
+            figure_caption(&ele).is_some_and(|caption| caption == normalized),
+            alt.trim().chars().count() > MAX_ALT_LENGTH,
+            is_spacer_image(&ele),
+        ];
+
+        if !invalid.contains(&true) {
+            continue;
+        }
+
+        let selector = get_unique_selector(&ele);
+
+        for ((_, elements), invalid) in validations.iter_mut().zip(invalid) {
+            if invalid {
+                elements.push(selector.clone());
+            }
+        }
+    }
+
+    validations
+        .into_iter()
+        .map(|(id, elements)| {
+            Validation::new(elements.is_empty(), id, elements, Default::default())
+        })
+        .collect()
+}
+
+/// the links beside the element, skipping the white space between them
+fn adjacent_links<'a>(ele: &ElementRef<'a>) -> impl Iterator<Item = ElementRef<'a>> {
+    let blank =
+        |n: &ego_tree::NodeRef<'_, Node>| n.value().as_text().is_some_and(|t| t.trim().is_empty());
+    let previous = ele.prev_siblings().find(|n| !blank(n));
+    let next = ele.next_siblings().find(|n| !blank(n));
+
+    previous
+        .into_iter()
+        .chain(next)
+        .filter_map(ElementRef::wrap)
+        .filter(|e| e.value().name() == "a" && e.attr("href").is_some())
+}
+
+/// validate the images of the links do not repeat the text of a link beside them
+pub fn validate_adjacent_link_alt(nodes: &ElementNodes) -> Validation {
+    let mut elements = Vec::new();
+
+    for node in nodes {
+        let ele = node.0;
+        let alts = descendant_elements(&ele)
+            .filter(|e| e.value().name() == "img")
+            .filter_map(|e| e.attr("alt").map(normalize_link_text))
+            .filter(|alt| !alt.is_empty())
+            .collect::<Vec<_>>();
+
+        if alts.is_empty() {
+            continue;
+        }
+
+        let duplicated = adjacent_links(&ele).any(|link| {
+            let text = normalize_link_text(&link.text().collect::<String>());
+            alts.contains(&text)
+        });
+
+        if duplicated {
+            elements.push(get_unique_selector(&ele));
+        }
+    }
+
+    Validation::new(elements.is_empty(), "EG3", elements, Default::default())
+}
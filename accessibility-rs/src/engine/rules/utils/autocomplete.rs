@@ -0,0 +1,341 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::wcag_base::Guideline;
+use crate::i18n::locales::get_message_i18n_str_raw;
+use accessibility_scraper::ElementRef;
+
+/// the control group an autofill field name belongs to <https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#autofill-field>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlGroup {
+    /// single line text
+    Text,
+    /// multiline text
+    Multiline,
+    /// passwords
+    Password,
+    /// urls
+    Url,
+    /// e-mail addresses
+    Email,
+    /// telephone numbers
+    Telephone,
+    /// numbers
+    Numeric,
+    /// months
+    Month,
+    /// dates
+    Date,
+}
+
+impl ControlGroup {
+    /// the control group name used for messages
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ControlGroup::Text => "Text",
+            ControlGroup::Multiline => "Multiline",
+            ControlGroup::Password => "Password",
+            ControlGroup::Url => "Url",
+            ControlGroup::Email => "Email",
+            ControlGroup::Telephone => "Telephone",
+            ControlGroup::Numeric => "Numeric",
+            ControlGroup::Month => "Month",
+            ControlGroup::Date => "Date",
+        }
+    }
+
+    /// the control types that can use a field name of the group
+    pub fn controls(&self) -> &'static [&'static str] {
+        match self {
+            ControlGroup::Text => &["hidden", "text", "search", "textarea", "select"],
+            ControlGroup::Multiline => &["hidden", "textarea", "select"],
+            ControlGroup::Password => {
+                &["hidden", "text", "search", "password", "textarea", "select"]
+            }
+            ControlGroup::Url => &["hidden", "text", "search", "url", "textarea", "select"],
+            ControlGroup::Email => &["hidden", "text", "search", "email", "textarea", "select"],
+            ControlGroup::Telephone => &["hidden", "text", "search", "tel", "textarea", "select"],
+            ControlGroup::Numeric => &["hidden", "text", "search", "number", "textarea", "select"],
+            ControlGroup::Month => &["hidden", "text", "search", "month", "textarea", "select"],
+            ControlGroup::Date => &["hidden", "text", "search", "date", "textarea", "select"],
+        }
+    }
+
+    /// the control type can use a field name of the group
+    pub fn allows(&self, control: &str) -> bool {
+        self.controls().contains(&control)
+    }
+}
+
+lazy_static! {
+    /// field names that are not contact information
+    static ref FIELD_NAMES: Vec<(&'static str, ControlGroup)> = Vec::from([
+        ("name", ControlGroup::Text),
+        ("honorific-prefix", ControlGroup::Text),
+        ("given-name", ControlGroup::Text),
+        ("additional-name", ControlGroup::Text),
+        ("family-name", ControlGroup::Text),
+        ("honorific-suffix", ControlGroup::Text),
+        ("nickname", ControlGroup::Text),
+        ("organization-title", ControlGroup::Text),
+        ("username", ControlGroup::Text),
+        ("new-password", ControlGroup::Password),
+        ("current-password", ControlGroup::Password),
+        ("one-time-code", ControlGroup::Password),
+        ("organization", ControlGroup::Text),
+        ("street-address", ControlGroup::Multiline),
+        ("address-line1", ControlGroup::Text),
+        ("address-line2", ControlGroup::Text),
+        ("address-line3", ControlGroup::Text),
+        ("address-level4", ControlGroup::Text),
+        ("address-level3", ControlGroup::Text),
+        ("address-level2", ControlGroup::Text),
+        ("address-level1", ControlGroup::Text),
+        ("country", ControlGroup::Text),
+        ("country-name", ControlGroup::Text),
+        ("postal-code", ControlGroup::Text),
+        ("cc-name", ControlGroup::Text),
+        ("cc-given-name", ControlGroup::Text),
+        ("cc-additional-name", ControlGroup::Text),
+        ("cc-family-name", ControlGroup::Text),
+        ("cc-number", ControlGroup::Text),
+        ("cc-exp", ControlGroup::Month),
+        ("cc-exp-month", ControlGroup::Numeric),
+        ("cc-exp-year", ControlGroup::Numeric),
+        ("cc-csc", ControlGroup::Text),
+        ("cc-type", ControlGroup::Text),
+        ("transaction-currency", ControlGroup::Text),
+        ("transaction-amount", ControlGroup::Numeric),
+        ("language", ControlGroup::Text),
+        ("bday", ControlGroup::Date),
+        ("bday-day", ControlGroup::Numeric),
+        ("bday-month", ControlGroup::Numeric),
+        ("bday-year", ControlGroup::Numeric),
+        ("sex", ControlGroup::Text),
+        ("url", ControlGroup::Url),
+        ("photo", ControlGroup::Url),
+    ]);
+    /// field names for contact information that can be prefixed with a contact type
+    static ref CONTACT_FIELD_NAMES: Vec<(&'static str, ControlGroup)> = Vec::from([
+        ("tel", ControlGroup::Telephone),
+        ("tel-country-code", ControlGroup::Text),
+        ("tel-national", ControlGroup::Text),
+        ("tel-area-code", ControlGroup::Text),
+        ("tel-local", ControlGroup::Text),
+        ("tel-local-prefix", ControlGroup::Text),
+        ("tel-local-suffix", ControlGroup::Text),
+        ("tel-extension", ControlGroup::Text),
+        ("email", ControlGroup::Email),
+        ("impp", ControlGroup::Url),
+    ]);
+    /// the words of a control name or id that hint the field collects information about the user
+    static ref PERSONAL_DATA_HINTS: Vec<&'static str> = Vec::from([
+        "name", "firstname", "lastname", "fullname", "surname", "username", "email", "mail",
+        "phone", "telephone", "tel", "mobile", "address", "street", "city", "zip", "zipcode",
+        "postal", "postcode", "country", "birthday", "bday",
+    ]);
+}
+
+/// the parsed autofill detail tokens of an autocomplete attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Autofill {
+    /// autofill is disabled
+    Off,
+    /// autofill is enabled without a purpose
+    On,
+    /// a field name with the control group it belongs to
+    Field(&'static str, ControlGroup),
+}
+
+/// parse the autocomplete value using the autofill detail tokens grammar returning the faulty value on failure.
+pub fn parse_autocomplete(value: &str) -> Result<Autofill, String> {
+    let value = value.to_ascii_lowercase();
+    let mut tokens = value.split_ascii_whitespace().collect::<Vec<_>>();
+
+    if tokens.is_empty() {
+        return Ok(Autofill::On);
+    }
+
+    if tokens.len() == 1 {
+        if tokens[0] == "off" {
+            return Ok(Autofill::Off);
+        } else if tokens[0] == "on" {
+            return Ok(Autofill::On);
+        }
+    }
+
+    if tokens.last() == Some(&"webauthn") {
+        tokens.pop();
+    }
+
+    let field = match tokens.pop() {
+        Some(token) => {
+            if let Some(field) = FIELD_NAMES.iter().find(|f| f.0 == token) {
+                field
+            } else if let Some(field) = CONTACT_FIELD_NAMES.iter().find(|f| f.0 == token) {
+                if let Some(contact) = tokens.last() {
+                    if ["home", "work", "mobile", "fax", "pager"].contains(contact) {
+                        tokens.pop();
+                    }
+                }
+                field
+            } else {
+                return Err(value);
+            }
+        }
+        _ => return Err(value),
+    };
+
+    if let Some(mode) = tokens.last() {
+        if *mode == "shipping" || *mode == "billing" {
+            tokens.pop();
+        }
+    }
+
+    if let Some(section) = tokens.last() {
+        if section.starts_with("section-") {
+            tokens.pop();
+        }
+    }
+
+    if tokens.is_empty() {
+        Ok(Autofill::Field(field.0, field.1))
+    } else {
+        Err(value)
+    }
+}
+
+/// the control type used to match autofill control groups
+pub fn control_type(ele: &ElementRef<'_>) -> String {
+    let name = ele.value().name();
+
+    if name == "input" {
+        match ele.attr("type") {
+            Some(t) if !t.trim().is_empty() => t.trim().to_ascii_lowercase(),
+            _ => "text".into(),
+        }
+    } else {
+        name.into()
+    }
+}
+
+/// the lowercase words of a control name or id split on separators and camel case
+fn name_tokens(value: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut prev_lower = false;
+
+    for c in value.chars() {
+        if !c.is_alphanumeric() {
+            prev_lower = false;
+            if !token.is_empty() {
+                tokens.push(std::mem::take(&mut token));
+            }
+            continue;
+        }
+        if c.is_uppercase() && prev_lower && !token.is_empty() {
+            tokens.push(std::mem::take(&mut token));
+        }
+        prev_lower = c.is_lowercase() || c.is_numeric();
+        token.extend(c.to_lowercase());
+    }
+
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+/// the control looks like it collects personal information about the user
+pub fn is_personal_data_field(ele: &ElementRef<'_>) -> bool {
+    let control = control_type(ele);
+
+    if control == "email" || control == "tel" {
+        true
+    } else if ["text", "search", "textarea", "select"].contains(&control.as_str()) {
+        [ele.attr("name"), ele.attr("id")]
+            .iter()
+            .any(|attr| match attr {
+                Some(attr) => name_tokens(attr)
+                    .iter()
+                    .any(|token| PERSONAL_DATA_HINTS.contains(&token.as_str())),
+                _ => false,
+            })
+    } else {
+        false
+    }
+}
+
+/// validate autocomplete values follow the autofill detail tokens grammar
+pub fn validate_autocomplete_faulty(nodes: &ElementNodes, locale: &str) -> Vec<Validation> {
+    let mut validations = Vec::new();
+
+    for ele in nodes {
+        if let Some(value) = ele.0.attr("autocomplete") {
+            if let Err(value) = parse_autocomplete(value) {
+                let message = t!(
+                    &get_message_i18n_str_raw(&Guideline::Adaptable, "H98", "5", "FaultyValue"),
+                    locale = locale,
+                    valuesStr = value
+                );
+                validations.push(Validation::new(
+                    false,
+                    "FaultyValue",
+                    vec![get_unique_selector(&ele.0)],
+                    message,
+                ));
+            }
+        }
+    }
+
+    validations
+}
+
+/// validate autocomplete field names belong to the control group of the element
+pub fn validate_autocomplete_control(nodes: &ElementNodes, locale: &str) -> Vec<Validation> {
+    let mut validations = Vec::new();
+
+    for ele in nodes {
+        if let Some(value) = ele.0.attr("autocomplete") {
+            if let Ok(Autofill::Field(field, group)) = parse_autocomplete(value) {
+                if !group.allows(&control_type(&ele.0)) {
+                    let message = t!(
+                        &get_message_i18n_str_raw(
+                            &Guideline::Adaptable,
+                            "H98",
+                            "5",
+                            &["InvalidAutoComplete", group.as_str()].join("_")
+                        ),
+                        locale = locale,
+                        x = field
+                    );
+                    validations.push(Validation::new(
+                        false,
+                        "InvalidAutoComplete",
+                        vec![get_unique_selector(&ele.0)],
+                        message,
+                    ));
+                }
+            }
+        }
+    }
+
+    validations
+}
+
+/// validate personal data fields do not turn autocomplete off
+pub fn validate_autocomplete_off(nodes: &ElementNodes) -> Validation {
+    let mut valid = true;
+    let mut elements = Vec::new();
+
+    for ele in nodes {
+        if let Some(value) = ele.0.attr("autocomplete") {
+            if parse_autocomplete(value) == Ok(Autofill::Off) && is_personal_data_field(&ele.0) {
+                valid = false;
+                elements.push(get_unique_selector(&ele.0))
+            }
+        }
+    }
+
+    Validation::new(valid, "Off", elements, Default::default())
+}
@@ -0,0 +1,70 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::{descendant_elements, is_hidden_subtree};
+use crate::engine::rules::utils::names::accessible_name;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use accessibility_scraper::ElementRef;
+
+/// the input types that submit the form
+const SUBMIT_INPUT_TYPES: [&str; 2] = ["submit", "image"];
+/// the button types that do not submit the form, any other or missing type is a submit button
+const NON_SUBMIT_BUTTON_TYPES: [&str; 2] = ["reset", "button"];
+
+/// the type attribute of the element lowercased
+fn control_type(ele: &ElementRef<'_>) -> String {
+    ele.attr("type")
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// the element submits its form <https://html.spec.whatwg.org/multipage/forms.html#concept-submit-button>
+pub fn is_submit_button(ele: &ElementRef<'_>) -> bool {
+    match ele.value().name() {
+        "button" => !NON_SUBMIT_BUTTON_TYPES.contains(&control_type(ele).as_str()),
+        "input" => SUBMIT_INPUT_TYPES.contains(&control_type(ele).as_str()),
+        _ => false,
+    }
+}
+
+/// the form contains a submit button or one is associated with it by the form attribute
+pub fn has_submit_button(form: &ElementRef<'_>) -> bool {
+    if descendant_elements(form).any(|e| is_submit_button(&e)) {
+        return true;
+    }
+
+    match form.value().id() {
+        Some(id) => form
+            .tree()
+            .nodes()
+            .filter_map(ElementRef::wrap)
+            .any(|e| e.attr("form") == Some(id) && is_submit_button(&e)),
+        _ => false,
+    }
+}
+
+/// validate the forms have a submit button
+pub fn validate_submit_button(nodes: &ElementNodes) -> Validation {
+    let elements = nodes
+        .iter()
+        .filter(|node| !has_submit_button(&node.0))
+        .map(|node| get_unique_selector(&node.0))
+        .collect::<Vec<_>>();
+
+    Validation::new(elements.is_empty(), "2", elements, Default::default())
+}
+
+/// validate the buttons have an accessible name from their content, an image or aria
+pub fn validate_button_name(nodes: &ElementNodes) -> Validation {
+    let elements = nodes
+        .iter()
+        .filter(|node| !is_hidden_subtree(&node.0) && accessible_name(&node.0).is_empty())
+        .map(|node| get_unique_selector(&node.0))
+        .collect::<Vec<_>>();
+
+    Validation::new(
+        elements.is_empty(),
+        "Button.Name",
+        elements,
+        Default::default(),
+    )
+}
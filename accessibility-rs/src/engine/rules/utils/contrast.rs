@@ -0,0 +1,585 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::focus::has_outline;
+use crate::engine::rules::utils::keyboard::{descendant_elements, is_disabled, is_focusable};
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::utils::styles::ComputedStyles;
+use crate::engine::rules::utils::visibility::Visibility;
+use crate::engine::rules::wcag_base::Guideline;
+use crate::i18n::locales::get_message_i18n_str_raw;
+use crate::Auditor;
+use accessibility_scraper::ElementRef;
+use accessibility_tree::style::values::{BackgroundImage, Display, OutlineStyle};
+use accessibility_tree::style::ComputedValues;
+use cssparser::RGBA;
+use rgb::RGB8;
+
+/// the canvas color painted behind the document
+pub const CANVAS: RGB8 = RGB8 {
+    r: 255,
+    g: 255,
+    b: 255,
+};
+
+/// elements that never render their text content
+const TEXT_IGNORE: [&str; 7] = [
+    "head", "title", "script", "style", "noscript", "template", "svg",
+];
+
+/// the contrast ratios required for normal and large text at a conformance level
+pub struct ContrastLevel {
+    /// the success criterion of the level
+    pub success_criteria: &'static str,
+    /// the techniques of the message keys
+    pub techniques: &'static str,
+    /// the ratio required for normal text
+    pub normal: f32,
+    /// the ratio required for large text
+    pub large: f32,
+}
+
+/// the contrast minimum of 1.4.3
+pub const CONTRAST_MINIMUM: ContrastLevel = ContrastLevel {
+    success_criteria: "3",
+    techniques: "G18_or_G145",
+    normal: 4.5,
+    large: 3.0,
+};
+
+/// the contrast enhanced of 1.4.6
+pub const CONTRAST_ENHANCED: ContrastLevel = ContrastLevel {
+    success_criteria: "6",
+    techniques: "G18_or_G17",
+    normal: 7.0,
+    large: 4.5,
+};
+
+/// the measured contrast of the text of an element
+#[derive(Clone, Debug)]
+pub struct TextContrast {
+    /// the text color composited on the background
+    pub foreground: RGB8,
+    /// the background color composited up to the canvas
+    pub background: RGB8,
+    /// the contrast ratio between the text and the background
+    pub ratio: f32,
+    /// the text is large scale
+    pub large: bool,
+    /// the text is placed on a background image or gradient
+    pub image: bool,
+}
+
+/// paint the color with its alpha over the backdrop
+pub fn composite(color: RGBA, backdrop: RGB8) -> RGB8 {
+    let alpha = color.alpha as f32 / 255.0;
+    let blend = |c: u8, b: u8| (c as f32 * alpha + b as f32 * (1.0 - alpha)).round() as u8;
+
+    RGB8::new(
+        blend(color.red, backdrop.r),
+        blend(color.green, backdrop.g),
+        blend(color.blue, backdrop.b),
+    )
+}
+
+/// the contrast ratio between two colors <https://www.w3.org/TR/WCAG22/#dfn-contrast-ratio>
+pub fn contrast_ratio(a: RGB8, b: RGB8) -> f32 {
+    contrast::contrast::<_, f32>(a, b)
+}
+
+/// the color as a hex string
+pub fn hex_color(color: RGB8) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+/// the D65 reference white of the CIELAB color space
+const WHITE_POINT: [f32; 3] = [0.95047, 1.0, 1.08883];
+
+/// convert the color to CIELAB <https://en.wikipedia.org/wiki/CIELAB_color_space>
+fn to_lab(color: RGB8) -> [f32; 3] {
+    let linear = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let (r, g, b) = (linear(color.r), linear(color.g), linear(color.b));
+    let xyz = [
+        0.4124 * r + 0.3576 * g + 0.1805 * b,
+        0.2126 * r + 0.7152 * g + 0.0722 * b,
+        0.0193 * r + 0.1192 * g + 0.9505 * b,
+    ];
+    let f = |t: f32| {
+        if t > 0.008856 {
+            t.cbrt()
+        } else {
+            7.787 * t + 16.0 / 116.0
+        }
+    };
+    let [x, y, z] = [
+        f(xyz[0] / WHITE_POINT[0]),
+        f(xyz[1] / WHITE_POINT[1]),
+        f(xyz[2] / WHITE_POINT[2]),
+    ];
+
+    [116.0 * y - 16.0, 500.0 * (x - y), 200.0 * (y - z)]
+}
+
+/// convert the CIELAB color to the closest color in the sRGB gamut
+fn from_lab([l, a, b]: [f32; 3]) -> RGB8 {
+    let y = (l + 16.0) / 116.0;
+    let x = a / 500.0 + y;
+    let z = y - b / 200.0;
+    let f = |t: f32| {
+        if t.powi(3) > 0.008856 {
+            t.powi(3)
+        } else {
+            (t - 16.0 / 116.0) / 7.787
+        }
+    };
+    let (x, y, z) = (
+        f(x) * WHITE_POINT[0],
+        f(y) * WHITE_POINT[1],
+        f(z) * WHITE_POINT[2],
+    );
+    let gamma = |c: f32| {
+        let c = if c <= 0.0031308 {
+            12.92 * c
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        };
+        (c.clamp(0.0, 1.0) * 255.0).round() as u8
+    };
+
+    RGB8::new(
+        gamma(3.2406 * x - 1.5372 * y - 0.4986 * z),
+        gamma(-0.9689 * x + 1.8758 * y + 0.0415 * z),
+        gamma(0.0557 * x - 0.2040 * y + 1.0570 * z),
+    )
+}
+
+/// the closest color to the color keeping the hue that reaches the contrast ratio against the other color.
+/// the lightness is searched towards black and white in CIELAB and the smallest change is suggested.
+pub fn suggest_color(color: RGB8, other: RGB8, required: f32) -> Option<RGB8> {
+    let [lightness, a, b] = to_lab(color);
+    let passes = |l: f32| {
+        let suggestion = from_lab([l, a, b]);
+        (contrast_ratio(suggestion, other) >= required).then_some(suggestion)
+    };
+
+    [0.0, 100.0]
+        .into_iter()
+        .filter(|&target| passes(target).is_some())
+        .map(|target| {
+            let (mut near, mut far) = (lightness, target);
+            // the contrast grows with the distance from the lightness of the color
+            for _ in 0..24 {
+                let mid = (near + far) / 2.0;
+                if passes(mid).is_some() {
+                    far = mid;
+                } else {
+                    near = mid;
+                }
+            }
+            (far, (far - lightness).abs())
+        })
+        .min_by(|x, y| x.1.total_cmp(&y.1))
+        .and_then(|(l, _)| passes(l))
+}
+
+/// the text is at least 18 point or 14 point bold <https://www.w3.org/TR/WCAG22/#dfn-large-scale>
+pub fn is_large_text(style: &ComputedValues) -> bool {
+    let pt = style.font.font_size.0.px * 0.75;
+
+    pt >= 18.0 || (pt >= 14.0 && style.font.font_weight.is_bold())
+}
+
+/// the background of the element composited from the canvas through all of the ancestors
+pub fn background_color(ele: &ElementRef<'_>, styles: &mut ComputedStyles<'_, '_>) -> RGB8 {
+    let mut layers = vec![*ele];
+    layers.extend(ele.ancestors().filter_map(ElementRef::wrap));
+
+    layers.iter().rev().fold(CANVAS, |backdrop, layer| {
+        let style = styles.style(layer);
+        composite(style.to_rgba(style.background.background_color), backdrop)
+    })
+}
+
+/// the element renders text of its own
+pub fn has_text(ele: &ElementRef<'_>) -> bool {
+    ele.children().any(|node| {
+        node.value()
+            .as_text()
+            .is_some_and(|text| !text.trim().is_empty())
+    })
+}
+
+/// measure the contrast of the text of the style on the background
+pub fn text_contrast(style: &ComputedValues, background: RGB8, image: bool) -> TextContrast {
+    let foreground = composite(style.color.color, background);
+
+    TextContrast {
+        foreground,
+        background,
+        ratio: contrast_ratio(foreground, background),
+        large: is_large_text(style),
+        image,
+    }
+}
+
+/// the background of the style paints over an image below it or paints an image
+fn paints_image(style: &ComputedValues, image: bool) -> bool {
+    let opaque = style.to_rgba(style.background.background_color).alpha == 255;
+
+    style.background.background_image == BackgroundImage::Image || (image && !opaque)
+}
+
+/// the message of a contrast failure at the level
+pub fn contrast_message(
+    contrast: &TextContrast,
+    required: f32,
+    level: &ContrastLevel,
+    locale: &str,
+) -> String {
+    let key = |section: &str| {
+        get_message_i18n_str_raw(
+            &Guideline::Distinguishable,
+            level.techniques,
+            level.success_criteria,
+            section,
+        )
+    };
+    let fail = t!(
+        &key("Fail"),
+        locale = locale,
+        required = required.to_string(),
+        value = format!("{:.2}", contrast.ratio)
+    );
+    let colors = t!(
+        &key("Fail.Colors"),
+        locale = locale,
+        foreground = hex_color(contrast.foreground),
+        background = hex_color(contrast.background)
+    );
+    let mut recommendations = Vec::new();
+
+    if let Some(color) = suggest_color(contrast.foreground, contrast.background, required) {
+        recommendations.push(t!(
+            &key("Fail.Recomendation.Text"),
+            locale = locale,
+            value = hex_color(color)
+        ));
+    }
+    if let Some(color) = suggest_color(contrast.background, contrast.foreground, required) {
+        recommendations.push(t!(
+            &key("Fail.Recomendation.Background"),
+            locale = locale,
+            value = hex_color(color)
+        ));
+    }
+
+    if recommendations.is_empty() {
+        [fail, colors].join(" ")
+    } else {
+        let recommendation = t!(&key("Fail.Recomendation"), locale = locale);
+
+        format!(
+            "{} {} {}{}.",
+            fail,
+            colors,
+            recommendation,
+            recommendations.join(", ")
+        )
+    }
+}
+
+/// measure the contrast of the text of every element walking the body in document order
+pub fn measure_text_contrast<'a>(auditor: &Auditor<'a>) -> Vec<(ElementRef<'a>, TextContrast)> {
+    let mut styles = ComputedStyles::new(auditor);
+    let mut measured = Vec::new();
+
+    for node in auditor.tree.get("body").into_iter().flatten() {
+        let (backdrop, image) = match node.0.parent().and_then(ElementRef::wrap) {
+            Some(parent) => (
+                background_color(&parent, &mut styles),
+                paints_image(&styles.style(&parent), false),
+            ),
+            _ => (CANVAS, false),
+        };
+        // carry the composited background and images down to the children
+        let mut stack = vec![(node.0, backdrop, image)];
+
+        while let Some((ele, backdrop, image)) = stack.pop() {
+            if TEXT_IGNORE.contains(&ele.value().name()) {
+                continue;
+            }
+
+            let style = styles.style(&ele);
+
+            // nothing below an element without a box is rendered
+            if style.box_.display == Display::None {
+                continue;
+            }
+
+            let background = composite(style.to_rgba(style.background.background_color), backdrop);
+            let image = paints_image(&style, image);
+
+            if has_text(&ele) && styles.visibility(&ele).is_rendered() {
+                measured.push((ele, text_contrast(&style, background, image)));
+            }
+
+            let children = ele
+                .children()
+                .filter_map(ElementRef::wrap)
+                .collect::<Vec<_>>();
+            stack.extend(
+                children
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, background, image)),
+            );
+        }
+    }
+
+    measured
+}
+
+/// validate the text of every element has enough contrast with its background at the level
+pub fn validate_text_contrast(auditor: &Auditor<'_>, level: &ContrastLevel) -> Vec<Validation> {
+    let mut validations = Vec::new();
+    let measured = auditor
+        .text_contrast
+        .get_or_init(|| measure_text_contrast(auditor));
+
+    for (ele, contrast) in measured.iter().filter(|(_, contrast)| !contrast.image) {
+        let required = if contrast.large {
+            level.large
+        } else {
+            level.normal
+        };
+
+        if contrast.ratio < required {
+            validations.push(Validation::new(
+                false,
+                "Fail",
+                vec![get_unique_selector(ele)],
+                contrast_message(contrast, required, level, auditor.locale),
+            ));
+        }
+    }
+
+    validations
+}
+
+/// notice the text placed on background images where the contrast needs to be reviewed at the level
+pub fn validate_text_contrast_image(
+    auditor: &Auditor<'_>,
+    level: &ContrastLevel,
+) -> Vec<Validation> {
+    let mut validations = Vec::new();
+    let measured = auditor
+        .text_contrast
+        .get_or_init(|| measure_text_contrast(auditor));
+
+    for (ele, contrast) in measured.iter().filter(|(_, contrast)| contrast.image) {
+        let required = if contrast.large {
+            level.large
+        } else {
+            level.normal
+        };
+        let message = t!(
+            &get_message_i18n_str_raw(
+                &Guideline::Distinguishable,
+                level.techniques,
+                level.success_criteria,
+                "BgImage"
+            ),
+            locale = auditor.locale,
+            required = required.to_string()
+        );
+
+        validations.push(Validation::new(
+            false,
+            "BgImage",
+            vec![get_unique_selector(ele)],
+            message,
+        ));
+    }
+
+    validations
+}
+
+/// the contrast required for user interface components and graphical objects of 1.4.11
+pub const NON_TEXT_CONTRAST: f32 = 3.0;
+
+/// roles of widgets with a visible boundary
+const UI_COMPONENT_ROLES: [&str; 9] = [
+    "button",
+    "checkbox",
+    "combobox",
+    "radio",
+    "searchbox",
+    "slider",
+    "spinbutton",
+    "switch",
+    "textbox",
+];
+
+/// the element is a user interface component identified by its boundary
+pub fn is_ui_component(ele: &ElementRef<'_>) -> bool {
+    match ele.value().name() {
+        "button" | "select" | "textarea" => true,
+        "input" => !matches!(
+            ele.attr("type")
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase()
+                .as_str(),
+            "hidden" | "image"
+        ),
+        _ => ele
+            .attr("role")
+            .unwrap_or_default()
+            .split_ascii_whitespace()
+            .next()
+            .is_some_and(|role| UI_COMPONENT_ROLES.contains(&role)),
+    }
+}
+
+/// the colors of the visible border sides of the style
+fn border_colors(style: &ComputedValues) -> Vec<RGBA> {
+    let b = &style.border;
+
+    [
+        (b.border_top_width, b.border_top_color),
+        (b.border_left_width, b.border_left_color),
+        (b.border_bottom_width, b.border_bottom_color),
+        (b.border_right_width, b.border_right_color),
+    ]
+    .iter()
+    .filter(|(width, _)| width.0.inner_px() > 0.0)
+    .map(|(_, color)| style.to_rgba(*color))
+    .collect()
+}
+
+/// the highest contrast of the boundary of the component against the adjacent background
+pub fn boundary_contrast(style: &ComputedValues, backdrop: RGB8) -> Option<f32> {
+    let background = composite(style.to_rgba(style.background.background_color), backdrop);
+    let mut ratios = border_colors(style)
+        .into_iter()
+        .map(|color| contrast_ratio(composite(color, backdrop), backdrop))
+        .collect::<Vec<_>>();
+
+    if background != backdrop {
+        ratios.push(contrast_ratio(background, backdrop));
+    }
+
+    // components without a border or background keep the appearance of the user agent
+    ratios.into_iter().reduce(f32::max)
+}
+
+/// the message of a non-text contrast failure
+fn non_text_message(
+    technique: &str,
+    id: &str,
+    ratio: f32,
+    background: RGB8,
+    ele: &ElementRef<'_>,
+    locale: &str,
+) -> String {
+    t!(
+        &get_message_i18n_str_raw(&Guideline::Distinguishable, technique, "11", id),
+        locale = locale,
+        element = ele.value().name(),
+        required = NON_TEXT_CONTRAST.to_string(),
+        value = format!("{:.2}", ratio),
+        background = hex_color(background)
+    )
+}
+
+/// validate the boundaries of the user interface components have enough contrast with the adjacent colors
+pub fn validate_component_contrast(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Vec<Validation> {
+    let mut styles = ComputedStyles::new(auditor);
+    let mut validations = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if is_ui_component(&ele)
+                && !is_disabled(&ele)
+                && styles.visibility(&ele) == Visibility::Visible
+            {
+                let backdrop = match ele.parent().and_then(ElementRef::wrap) {
+                    Some(parent) => background_color(&parent, &mut styles),
+                    _ => CANVAS,
+                };
+                let style = styles.style(&ele);
+
+                if let Some(ratio) = boundary_contrast(&style, backdrop) {
+                    if ratio < NON_TEXT_CONTRAST {
+                        validations.push(Validation::new(
+                            false,
+                            "Boundary",
+                            vec![get_unique_selector(&ele)],
+                            non_text_message(
+                                "G209",
+                                "Boundary",
+                                ratio,
+                                backdrop,
+                                &ele,
+                                auditor.locale,
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    validations
+}
+
+/// validate the author focus outlines have enough contrast with the adjacent background
+pub fn validate_focus_indicator_contrast(
+    nodes: &ElementNodes,
+    auditor: &Auditor<'_>,
+) -> Vec<Validation> {
+    let mut styles = ComputedStyles::new(auditor);
+    let mut validations = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if is_focusable(&ele) && styles.visibility(&ele) == Visibility::Visible {
+                let focus = styles.focus_style(&ele);
+
+                // the automatic outline is drawn by the user agent
+                if !matches!(focus.outline.outline_style, OutlineStyle::Auto) && has_outline(&focus)
+                {
+                    let backdrop = match ele.parent().and_then(ElementRef::wrap) {
+                        Some(parent) => background_color(&parent, &mut styles),
+                        _ => CANVAS,
+                    };
+                    let outline = composite(focus.to_rgba(focus.outline.outline_color), backdrop);
+                    let ratio = contrast_ratio(outline, backdrop);
+
+                    if ratio < NON_TEXT_CONTRAST {
+                        validations.push(Validation::new(
+                            false,
+                            "FocusIndicator",
+                            vec![get_unique_selector(&ele)],
+                            non_text_message(
+                                "G195",
+                                "FocusIndicator",
+                                ratio,
+                                backdrop,
+                                &ele,
+                                auditor.locale,
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    validations
+}
@@ -0,0 +1,84 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::{descendant_elements, is_focusable};
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::utils::styles::ComputedStyles;
+use crate::engine::rules::utils::visibility::Visibility;
+use crate::Auditor;
+use accessibility_tree::style::values::{BoxShadow, LineStyle, OutlineStyle};
+use accessibility_tree::style::ComputedValues;
+
+/// the style draws a visible outline around the element
+pub fn has_outline(style: &ComputedValues) -> bool {
+    let visible = style.to_rgba(style.outline.outline_color).alpha > 0;
+
+    match style.outline.outline_style {
+        OutlineStyle::Auto => true,
+        OutlineStyle::Style(LineStyle::None | LineStyle::Hidden) => false,
+        OutlineStyle::Style(_) => visible && style.outline.outline_width.0.inner_px() > 0.0,
+    }
+}
+
+/// the border of the style is drawn differently than the other style
+fn border_changed(style: &ComputedValues, other: &ComputedValues) -> bool {
+    let sides = |s: &ComputedValues| {
+        let b = &s.border;
+        [
+            (
+                b.border_top_width.0.inner_px(),
+                s.to_rgba(b.border_top_color),
+            ),
+            (
+                b.border_left_width.0.inner_px(),
+                s.to_rgba(b.border_left_color),
+            ),
+            (
+                b.border_bottom_width.0.inner_px(),
+                s.to_rgba(b.border_bottom_color),
+            ),
+            (
+                b.border_right_width.0.inner_px(),
+                s.to_rgba(b.border_right_color),
+            ),
+        ]
+    };
+
+    sides(style)
+        .iter()
+        .zip(sides(other).iter())
+        .any(|(a, b)| (a.0 > 0.0 || b.0 > 0.0) && a != b)
+}
+
+/// the focus style adds an indicator other than the outline
+pub fn has_focus_indicator(style: &ComputedValues, focus: &ComputedValues) -> bool {
+    border_changed(style, focus)
+        || style.to_rgba(style.background.background_color)
+            != focus.to_rgba(focus.background.background_color)
+        || (focus.background.box_shadow == BoxShadow::Shadow
+            && style.background.box_shadow == BoxShadow::None)
+}
+
+/// validate focusable elements keep a visible focus indicator when the outline is removed
+pub fn validate_focus_visible(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Validation {
+    let mut styles = ComputedStyles::new(auditor);
+    let mut valid = true;
+    let mut elements = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if is_focusable(&ele) && styles.visibility(&ele) == Visibility::Visible {
+                let focus = styles.focus_style(&ele);
+
+                if !has_outline(&focus) {
+                    let style = styles.style(&ele);
+
+                    if !has_focus_indicator(&style, &focus) {
+                        valid = false;
+                        elements.push(get_unique_selector(&ele))
+                    }
+                }
+            }
+        }
+    }
+
+    Validation::new(valid, "", elements, Default::default())
+}
@@ -0,0 +1,159 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::nodes::{get_unique_selector, has_aria_label, ElementNodes};
+use crate::engine::rules::wcag_base::Guideline;
+use crate::i18n::locales::get_message_i18n_str_raw;
+use accessibility_scraper::{ElementRef, Selector};
+
+/// the input type of the element lowercased
+pub fn input_type(ele: &ElementRef<'_>) -> String {
+    ele.attr("type")
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// the form owner and name of a group of controls
+type GroupKey<'a> = (Option<ego_tree::NodeId>, &'a str);
+
+/// the form owner and name that make up a group of controls
+fn group_key<'a>(ele: &ElementRef<'a>) -> Option<GroupKey<'a>> {
+    match ele.value().attr("name") {
+        Some(name) if !name.trim().is_empty() => {
+            let form = ele
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .find(|e| e.value().name() == "form")
+                .map(|e| e.id());
+            Some((form, name))
+        }
+        _ => None,
+    }
+}
+
+/// group the controls of the input type by the form owner and name in document order
+pub fn control_groups<'a>(
+    nodes: &ElementNodes<'a>,
+    control: &str,
+) -> Vec<(GroupKey<'a>, Vec<ElementRef<'a>>)> {
+    let mut groups: Vec<(GroupKey<'a>, Vec<ElementRef<'a>>)> = Vec::new();
+
+    for ele in nodes {
+        if input_type(&ele.0) == control {
+            if let Some(key) = group_key(&ele.0) {
+                match groups.iter_mut().find(|g| g.0 == key) {
+                    Some(group) => group.1.push(ele.0),
+                    _ => groups.push((key, vec![ele.0])),
+                }
+            }
+        }
+    }
+
+    groups
+}
+
+/// a fieldset with a legend that has text
+pub fn is_fieldset_with_legend(ele: &ElementRef<'_>) -> bool {
+    ele.value().name() == "fieldset"
+        && ele
+            .children()
+            .filter_map(ElementRef::wrap)
+            .any(|e| e.value().name() == "legend" && e.text().any(|t| !t.trim().is_empty()))
+}
+
+/// a group or radiogroup role with an accessible name
+pub fn is_named_group(ele: &ElementRef<'_>) -> bool {
+    match ele.attr("role") {
+        Some(role) => (role == "group" || role == "radiogroup") && has_aria_label(ele),
+        _ => false,
+    }
+}
+
+/// the control is contained in a fieldset with legend or a named group
+pub fn is_grouped(ele: &ElementRef<'_>) -> bool {
+    ele.ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|e| is_fieldset_with_legend(&e) || is_named_group(&e))
+}
+
+/// validate groups of controls sharing a name are grouped with a description
+pub fn validate_ungrouped_controls(
+    nodes: &ElementNodes,
+    control: &str,
+    min_size: usize,
+) -> Validation {
+    let mut valid = true;
+    let mut elements = Vec::new();
+
+    for (_, group) in control_groups(nodes, control) {
+        if group.len() >= min_size && !group.iter().all(is_grouped) {
+            valid = false;
+            elements.extend(group.iter().map(get_unique_selector));
+        }
+    }
+
+    Validation::new(valid, "SameName", elements, Default::default())
+}
+
+/// validate radio groups contain more than one radio button
+pub fn validate_single_radio(nodes: &ElementNodes, locale: &str) -> Vec<Validation> {
+    let mut validations = Vec::new();
+
+    for ((_, name), group) in control_groups(nodes, "radio") {
+        if group.len() == 1 {
+            let message = t!(
+                &get_message_i18n_str_raw(&Guideline::Adaptable, "H71", "1", "SingleRadio"),
+                locale = locale,
+                name = name
+            );
+            validations.push(Validation::new(
+                false,
+                "SingleRadio",
+                vec![get_unique_selector(&group[0])],
+                message,
+            ));
+        }
+    }
+
+    validations
+}
+
+/// validate fieldsets only contain radio buttons of a single group
+pub fn validate_fieldset_radio_names(nodes: &ElementNodes, locale: &str) -> Vec<Validation> {
+    let selector = unsafe { Selector::parse("input").unwrap_unchecked() };
+    let mut validations = Vec::new();
+
+    for ele in nodes {
+        let fieldset = ele.0;
+        let mut names: Vec<&str> = Vec::new();
+
+        for radio in fieldset.select(&selector) {
+            let owner = radio
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .find(|e| e.value().name() == "fieldset");
+
+            if input_type(&radio) == "radio" && owner == Some(fieldset) {
+                let name = radio.value().attr("name").unwrap_or_default();
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+
+        if names.len() > 1 {
+            let message = t!(
+                &get_message_i18n_str_raw(&Guideline::Adaptable, "H71", "1", "MixedNames"),
+                locale = locale,
+                names = names.join(", ")
+            );
+            validations.push(Validation::new(
+                false,
+                "MixedNames",
+                vec![get_unique_selector(&fieldset)],
+                message,
+            ));
+        }
+    }
+
+    validations
+}
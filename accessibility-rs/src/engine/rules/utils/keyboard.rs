@@ -0,0 +1,296 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::names::is_hidden_from_at;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::wcag_base::Guideline;
+use crate::i18n::locales::get_message_i18n_str_raw;
+use accessibility_scraper::ElementRef;
+
+/// roles of widgets that are operated with the keyboard <https://www.w3.org/TR/wai-aria-1.2/#widget_roles>
+const INTERACTIVE_ROLES: [&str; 18] = [
+    "button",
+    "checkbox",
+    "combobox",
+    "gridcell",
+    "link",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "scrollbar",
+    "searchbox",
+    "slider",
+    "spinbutton",
+    "switch",
+    "tab",
+    "textbox",
+    "treeitem",
+];
+
+/// mouse event handlers that activate content
+const ACTIVATION_HANDLERS: [&str; 3] = ["onclick", "onmousedown", "ondblclick"];
+
+/// mouse event handlers with the keyboard event handlers that provide the same function and the message id
+const MOUSE_HANDLERS: [(&str, &[&str], &str); 7] = [
+    ("onclick", &["onkeydown", "onkeyup", "onkeypress"], "Click"),
+    (
+        "ondblclick",
+        &["onkeydown", "onkeyup", "onkeypress"],
+        "DblClick",
+    ),
+    ("onmousedown", &["onkeydown"], "MouseDown"),
+    ("onmouseup", &["onkeyup"], "MouseUp"),
+    ("onmouseover", &["onfocus", "onfocusin"], "MouseOver"),
+    ("onmouseout", &["onblur", "onfocusout"], "MouseOut"),
+    ("onmousemove", &["onkeydown", "onkeyup"], "MouseMove"),
+];
+
+/// all elements in the subtree of the node
+pub fn descendant_elements<'a>(ele: &ElementRef<'a>) -> impl Iterator<Item = ElementRef<'a>> {
+    ele.descendants().skip(1).filter_map(ElementRef::wrap)
+}
+
+/// the element is a disabled control
+pub fn is_disabled(ele: &ElementRef<'_>) -> bool {
+    ele.value().attr("disabled").is_some()
+        || ele.attr("aria-disabled").unwrap_or_default().trim() == "true"
+}
+
+/// the element or an ancestor is hidden from assistive technology by markup
+pub fn is_hidden_subtree(ele: &ElementRef<'_>) -> bool {
+    is_hidden_from_at(ele)
+        || ele
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .any(|e| is_hidden_from_at(&e))
+}
+
+/// the parsed tabindex attribute of the element
+pub fn tabindex(ele: &ElementRef<'_>) -> Option<i32> {
+    match ele.attr("tabindex") {
+        Some(index) => index.trim().parse::<i32>().ok(),
+        _ => None,
+    }
+}
+
+/// the element is in the sequential focus order without a tabindex
+pub fn is_natively_focusable(ele: &ElementRef<'_>) -> bool {
+    let focusable = match ele.value().name() {
+        "a" | "area" => ele.attr("href").is_some(),
+        "button" | "select" | "textarea" | "iframe" | "summary" => true,
+        "input" => !ele
+            .attr("type")
+            .unwrap_or_default()
+            .trim()
+            .eq_ignore_ascii_case("hidden"),
+        "audio" | "video" => ele.attr("controls").is_some(),
+        _ => false,
+    };
+
+    (focusable && ele.value().attr("disabled").is_none())
+        || match ele.attr("contenteditable") {
+            Some(editable) => !editable.trim().eq_ignore_ascii_case("false"),
+            _ => false,
+        }
+}
+
+/// the element can be reached with the keyboard natively or with a non negative tabindex
+pub fn is_focusable(ele: &ElementRef<'_>) -> bool {
+    is_natively_focusable(ele) || tabindex(ele).is_some_and(|index| index >= 0)
+}
+
+/// the element has the role of an interactive widget
+pub fn has_interactive_role(ele: &ElementRef<'_>) -> bool {
+    match ele.attr("role") {
+        Some(role) => role
+            .split_ascii_whitespace()
+            .next()
+            .is_some_and(|role| INTERACTIVE_ROLES.contains(&role)),
+        _ => false,
+    }
+}
+
+/// the element is operated with the mouse or exposed as a widget
+pub fn is_interactive(ele: &ElementRef<'_>) -> bool {
+    has_interactive_role(ele)
+        || ACTIVATION_HANDLERS
+            .iter()
+            .any(|handler| ele.attr(handler).is_some())
+}
+
+/// an ancestor of the element is in the focus order taking the keyboard interaction
+fn has_focusable_ancestor(ele: &ElementRef<'_>) -> bool {
+    ele.ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|e| is_focusable(&e))
+}
+
+/// validate mouse operated elements and widgets can be reached with the keyboard
+pub fn validate_not_focusable(nodes: &ElementNodes) -> Validation {
+    let mut valid = true;
+    let mut elements = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if is_interactive(&ele)
+                && !is_focusable(&ele)
+                && !is_disabled(&ele)
+                && !is_hidden_subtree(&ele)
+                && !has_focusable_ancestor(&ele)
+            {
+                valid = false;
+                elements.push(get_unique_selector(&ele))
+            }
+        }
+    }
+
+    Validation::new(valid, "NotFocusable", elements, Default::default())
+}
+
+/// validate mouse event handlers have keyboard event handlers that provide the same function
+pub fn validate_mouse_only_handlers(nodes: &ElementNodes) -> Vec<Validation> {
+    let mut validations = Vec::new();
+
+    for (handler, keyboard, id) in MOUSE_HANDLERS {
+        let mut valid = true;
+        let mut elements = Vec::new();
+
+        for node in nodes {
+            for ele in descendant_elements(&node.0) {
+                // native controls fire the click event from the keyboard
+                let native_click = handler == "onclick" && is_natively_focusable(&ele);
+
+                if ele.attr(handler).is_some()
+                    && !native_click
+                    && !keyboard.iter().any(|k| ele.attr(k).is_some())
+                {
+                    valid = false;
+                    elements.push(get_unique_selector(&ele))
+                }
+            }
+        }
+
+        validations.push(Validation::new(valid, id, elements, Default::default()));
+    }
+
+    validations
+}
+
+/// validate the tab order is not changed with positive tabindex values
+pub fn validate_positive_tabindex(nodes: &ElementNodes) -> Validation {
+    let mut valid = true;
+    let mut elements = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if tabindex(&ele).is_some_and(|index| index > 0) {
+                valid = false;
+                elements.push(get_unique_selector(&ele))
+            }
+        }
+    }
+
+    Validation::new(valid, "", elements, Default::default())
+}
+
+/// validate tabindex does not add non-interactive content to the focus order
+pub fn validate_non_interactive_tabindex(nodes: &ElementNodes) -> Validation {
+    let mut valid = true;
+    let mut elements = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if tabindex(&ele).is_some_and(|index| index >= 0)
+                && !is_natively_focusable(&ele)
+                && !is_interactive(&ele)
+                && ele.attr("role").unwrap_or_default().trim().is_empty()
+            {
+                valid = false;
+                elements.push(get_unique_selector(&ele))
+            }
+        }
+    }
+
+    Validation::new(valid, "NonInteractive", elements, Default::default())
+}
+
+/// roles that cannot contain interactive descendants
+const NO_INTERACTIVE_DESCENDANT_ROLES: [&str; 11] = [
+    "button",
+    "checkbox",
+    "link",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "slider",
+    "switch",
+    "tab",
+];
+
+/// the element is interactive content of the html content model <https://html.spec.whatwg.org/multipage/dom.html#interactive-content>
+pub fn is_interactive_content(ele: &ElementRef<'_>) -> bool {
+    let native = match ele.value().name() {
+        "a" => ele.attr("href").is_some(),
+        "button" | "details" | "embed" | "iframe" | "label" | "select" | "textarea" => true,
+        "img" => ele.attr("usemap").is_some(),
+        "input" => !ele
+            .attr("type")
+            .unwrap_or_default()
+            .trim()
+            .eq_ignore_ascii_case("hidden"),
+        "audio" | "video" => ele.attr("controls").is_some(),
+        _ => false,
+    };
+
+    native || ele.attr("tabindex").is_some() || has_interactive_role(ele)
+}
+
+/// the element does not allow interactive content in its subtree
+fn forbids_interactive_descendants(ele: &ElementRef<'_>) -> bool {
+    match ele.attr("role") {
+        Some(role) if !role.trim().is_empty() => role
+            .split_ascii_whitespace()
+            .next()
+            .is_some_and(|role| NO_INTERACTIVE_DESCENDANT_ROLES.contains(&role)),
+        _ => matches!(ele.value().name(), "a" | "button"),
+    }
+}
+
+/// validate interactive content is not nested inside links, buttons and widgets
+pub fn validate_nested_interactive(nodes: &ElementNodes, locale: &str) -> Vec<Validation> {
+    let mut validations = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if is_interactive_content(&ele) {
+                if let Some(parent) = ele
+                    .ancestors()
+                    .filter_map(ElementRef::wrap)
+                    .find(forbids_interactive_descendants)
+                {
+                    let message = t!(
+                        &get_message_i18n_str_raw(
+                            &Guideline::Compatible,
+                            "H91",
+                            "2",
+                            "NestedInteractive"
+                        ),
+                        locale = locale,
+                        child = ele.value().name(),
+                        parent = parent.value().name()
+                    );
+                    validations.push(Validation::new(
+                        false,
+                        "NestedInteractive",
+                        vec![get_unique_selector(&ele)],
+                        message,
+                    ));
+                }
+            }
+        }
+    }
+
+    validations
+}
@@ -0,0 +1,262 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::descendant_elements;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use accessibility_scraper::ElementRef;
+
+/// the two letter primary language subtags of the IANA language subtag registry <https://www.iana.org/assignments/language-subtag-registry>
+const PRIMARY_LANGUAGES: [&str; 190] = [
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bh",
+    "bi", "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da",
+    "de", "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr",
+    "fy", "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz",
+    "ia", "id", "ie", "ig", "ii", "ik", "in", "io", "is", "it", "iu", "iw", "ja", "ji", "jv", "jw",
+    "ka", "kg", "ki", "kj", "kk", "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la",
+    "lb", "lg", "li", "ln", "lo", "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mo", "mr",
+    "ms", "mt", "my", "na", "nb", "nd", "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj",
+    "om", "or", "os", "pa", "pi", "pl", "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc",
+    "sd", "se", "sg", "sh", "si", "sk", "sl", "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv",
+    "sw", "ta", "te", "tg", "th", "ti", "tk", "tl", "tn", "to", "tr", "ts", "tt", "tw", "ty", "ug",
+    "uk", "ur", "uz", "ve", "vi", "vo", "wa", "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+/// a subset of the three letter primary language subtags of the registry in common use, languages with a two letter subtag are not registered by three letters
+const REGISTERED_LANGUAGES: [&str; 217] = [
+    "ace", "ady", "aii", "ain", "akk", "alt", "ang", "arb", "arc", "arn", "ary", "arz", "ase",
+    "ast", "avk", "awa", "bal", "ban", "bcl", "bem", "ber", "bfi", "bgc", "bho", "bjn", "bua",
+    "bug", "byn", "cdo", "ceb", "chk", "chr", "chy", "cjy", "ckb", "cmn", "cop", "crh", "crk",
+    "csb", "dak", "dar", "doi", "dsb", "efi", "egy", "enm", "fil", "fon", "fro", "frr", "fsl",
+    "fur", "gaa", "gan", "gaz", "gez", "gil", "glk", "gom", "gor", "got", "grc", "gsw", "hak",
+    "haw", "hbo", "hif", "hil", "hmn", "hne", "hsb", "hsn", "ilo", "inh", "jbo", "kab", "kac",
+    "kam", "kbd", "kbp", "kea", "kha", "kjh", "kln", "kmb", "kmr", "koi", "kok", "kos", "kpv",
+    "krc", "kri", "krl", "ksh", "ksw", "kum", "lad", "lbe", "lez", "lfn", "lij", "liv", "lkt",
+    "lmo", "loz", "lrc", "ltg", "lua", "luo", "lus", "lzh", "lzz", "mad", "mag", "mai", "mak",
+    "mdf", "men", "mhr", "mic", "min", "mis", "mni", "mnw", "moh", "mos", "mrj", "mul", "mwl",
+    "mwr", "myv", "mzn", "nah", "nan", "nap", "nds", "new", "niu", "nod", "non", "nov", "nqo",
+    "nso", "nyn", "pag", "pam", "pap", "pau", "pcm", "pnt", "pon", "prs", "quc", "quz", "raj",
+    "rar", "rom", "rue", "rup", "ryu", "sah", "sas", "sat", "scn", "sco", "sdh", "sgn", "sgs",
+    "shn", "sid", "sma", "smj", "smn", "sms", "sus", "sux", "sva", "swh", "syc", "syr", "szl",
+    "tcy", "tem", "tet", "tig", "tiv", "tkl", "tlh", "toi", "tok", "tpi", "tsg", "tts", "tum",
+    "tvl", "tyv", "tzm", "tzo", "udm", "umb", "und", "vec", "vep", "vot", "vro", "wal", "war",
+    "wuu", "xal", "xmf", "yap", "yua", "yue", "zgh", "zxx", "zza",
+];
+
+/// the irregular and regular grandfathered tags that do not follow the syntax
+const GRANDFATHERED: [&str; 26] = [
+    "en-gb-oed",
+    "i-ami",
+    "i-bnn",
+    "i-default",
+    "i-enochian",
+    "i-hak",
+    "i-klingon",
+    "i-lux",
+    "i-mingo",
+    "i-navajo",
+    "i-pwn",
+    "i-tao",
+    "i-tay",
+    "i-tsu",
+    "sgn-be-fr",
+    "sgn-be-nl",
+    "sgn-ch-de",
+    "art-lojban",
+    "cel-gaulish",
+    "no-bok",
+    "no-nyn",
+    "zh-guoyu",
+    "zh-hakka",
+    "zh-min",
+    "zh-min-nan",
+    "zh-xiang",
+];
+
+/// a language tag split into the subtags <https://www.rfc-editor.org/rfc/rfc5646#section-2.1>
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LanguageTag {
+    /// the primary language subtag
+    pub language: String,
+    /// the extended language subtags
+    pub extlangs: Vec<String>,
+    /// the script subtag
+    pub script: Option<String>,
+    /// the region subtag
+    pub region: Option<String>,
+    /// the variant subtags
+    pub variants: Vec<String>,
+    /// the extension subtags by singleton
+    pub extensions: Vec<(String, Vec<String>)>,
+    /// the private use subtags
+    pub private_use: Vec<String>,
+}
+
+/// the subtag is made of letters with a length in the range
+fn is_alpha(subtag: &str, len: std::ops::RangeInclusive<usize>) -> bool {
+    len.contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// the subtag is made of letters and digits with a length in the range
+fn is_alphanumeric(subtag: &str, len: std::ops::RangeInclusive<usize>) -> bool {
+    len.contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+/// the subtag is a variant of five to eight characters or four starting with a digit
+fn is_variant(subtag: &str) -> bool {
+    is_alphanumeric(subtag, 5..=8)
+        || is_alphanumeric(subtag, 4..=4) && subtag.as_bytes()[0].is_ascii_digit()
+}
+
+impl LanguageTag {
+    /// parse a well-formed language tag ignoring the case of the subtags
+    pub fn parse(tag: &str) -> Option<Self> {
+        let tag = tag.trim().to_ascii_lowercase();
+
+        if GRANDFATHERED.contains(&tag.as_str()) {
+            return Some(LanguageTag {
+                language: tag,
+                ..Default::default()
+            });
+        }
+
+        let subtags = tag.split('-').collect::<Vec<_>>();
+
+        if subtags.iter().any(|s| !is_alphanumeric(s, 1..=8)) {
+            return None;
+        }
+
+        let mut language_tag = LanguageTag::default();
+        let mut subtags = subtags.into_iter().peekable();
+
+        if subtags.peek() != Some(&"x") {
+            let language = subtags.next()?;
+
+            // four letters are reserved for future use
+            if !is_alpha(language, 2..=3) && !is_alpha(language, 5..=8) {
+                return None;
+            }
+            language_tag.language = language.into();
+
+            if language.len() <= 3 {
+                while language_tag.extlangs.len() < 3 {
+                    match subtags.next_if(|s| is_alpha(s, 3..=3)) {
+                        Some(extlang) => language_tag.extlangs.push(extlang.into()),
+                        _ => break,
+                    }
+                }
+            }
+
+            language_tag.script = subtags.next_if(|s| is_alpha(s, 4..=4)).map(Into::into);
+            language_tag.region = subtags
+                .next_if(|s| {
+                    is_alpha(s, 2..=2) || s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit())
+                })
+                .map(Into::into);
+
+            while let Some(variant) = subtags.next_if(|s| is_variant(s)) {
+                if language_tag.variants.iter().any(|v| v == variant) {
+                    return None;
+                }
+                language_tag.variants.push(variant.into());
+            }
+
+            while let Some(singleton) = subtags.next_if(|s| s.len() == 1 && *s != "x") {
+                let mut extension = Vec::new();
+
+                while let Some(subtag) = subtags.next_if(|s| is_alphanumeric(s, 2..=8)) {
+                    extension.push(subtag.into());
+                }
+                if extension.is_empty()
+                    || language_tag.extensions.iter().any(|(s, _)| s == singleton)
+                {
+                    return None;
+                }
+                language_tag.extensions.push((singleton.into(), extension));
+            }
+        }
+
+        if subtags.next_if(|s| *s == "x").is_some() {
+            language_tag.private_use = subtags.by_ref().map(Into::into).collect();
+
+            if language_tag.private_use.is_empty() {
+                return None;
+            }
+        }
+
+        if subtags.next().is_some() {
+            return None;
+        }
+
+        Some(language_tag)
+    }
+
+    /// the primary language is in the registry or reserved for local use
+    pub fn is_registered(&self) -> bool {
+        let language = self.language.as_str();
+
+        match language.len() {
+            // private use tags and grandfathered tags
+            0 => true,
+            _ if language.contains('-') => true,
+            2 => PRIMARY_LANGUAGES.contains(&language),
+            3 => REGISTERED_LANGUAGES.contains(&language) || ("qaa"..="qtz").contains(&language),
+            _ => false,
+        }
+    }
+}
+
+/// the language tag is well-formed and the primary language is registered
+pub fn is_valid_language_tag(tag: &str) -> bool {
+    LanguageTag::parse(tag).is_some_and(|tag| tag.is_registered())
+}
+
+/// the lang and xml:lang attributes of the element specify the same language when both are set
+pub fn is_language_consistent(ele: &ElementRef<'_>) -> bool {
+    match (ele.attr("lang"), ele.attr("xml:lang")) {
+        (Some(lang), Some(xml_lang)) => lang.trim().eq_ignore_ascii_case(xml_lang.trim()),
+        _ => true,
+    }
+}
+
+/// the language attribute is empty to mark an unknown language or a valid language tag
+pub fn is_valid_language_attr(ele: &ElementRef<'_>, attr: &str) -> bool {
+    match ele.attr(attr) {
+        Some(lang) => lang.trim().is_empty() || is_valid_language_tag(lang),
+        _ => true,
+    }
+}
+
+/// validate the language of every element inside the document
+pub fn validate_language_of_parts(nodes: &ElementNodes) -> Vec<Validation> {
+    let mut lang = Vec::new();
+    let mut xml_lang = Vec::new();
+    let mut mismatch = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if !is_valid_language_attr(&ele, "lang") {
+                lang.push(get_unique_selector(&ele));
+            }
+            if !is_valid_language_attr(&ele, "xml:lang") {
+                xml_lang.push(get_unique_selector(&ele));
+            }
+            if !is_language_consistent(&ele) {
+                mismatch.push(get_unique_selector(&ele));
+            }
+        }
+    }
+
+    vec![
+        Validation::new(lang.is_empty(), "1.Lang", lang, Default::default()),
+        Validation::new(
+            xml_lang.is_empty(),
+            "1.XmlLang",
+            xml_lang,
+            Default::default(),
+        ),
+        Validation::new(
+            mismatch.is_empty(),
+            "1.Mismatch",
+            mismatch,
+            Default::default(),
+        ),
+    ]
+}
@@ -0,0 +1,329 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::contrast::{background_color, composite, contrast_ratio};
+use crate::engine::rules::utils::names::{accessible_name, content_text, normalize_text};
+use crate::engine::rules::utils::nodes::{get_element_by_id, get_unique_selector, ElementNodes};
+use crate::engine::rules::utils::styles::ComputedStyles;
+use crate::engine::rules::utils::target::is_inline_text_target;
+use crate::engine::rules::utils::visibility::Visibility;
+use crate::engine::rules::wcag_base::Guideline;
+use crate::i18n::locales::{get_locale_from_lang, get_message_i18n_str_raw, get_phrases_i18n};
+use crate::Auditor;
+use accessibility_scraper::ElementRef;
+use accessibility_tree::style::values::{LineStyle, TextDecorationLine};
+use accessibility_tree::style::ComputedValues;
+
+/// elements that provide the programmatically determined context of a link
+const LINK_CONTEXT: [&str; 6] = ["p", "li", "td", "th", "dd", "figcaption"];
+
+/// the contrast required between a link and the surrounding text when color is the only cue
+const LINK_TEXT_CONTRAST: f32 = 3.0;
+
+/// the locale of the document content falling back to the audit locale
+pub fn content_locale(auditor: &Auditor<'_>) -> String {
+    let root = auditor.document.root_element();
+    let lang = root
+        .attr("lang")
+        .or_else(|| root.attr("xml:lang"))
+        .unwrap_or_default();
+
+    if lang.trim().is_empty() {
+        auditor.locale.into()
+    } else {
+        get_locale_from_lang(lang)
+    }
+}
+
+/// strip punctuation and symbols around the text for comparing link texts
+pub fn normalize_link_text(text: &str) -> String {
+    normalize_text(text)
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// the link text is a generic phrase of the locale
+pub fn is_ambiguous_link_text(text: &str, phrases: &[String]) -> bool {
+    let text = normalize_link_text(text);
+    !text.is_empty() && phrases.contains(&text)
+}
+
+/// the text of the programmatically determined context of the link
+pub fn link_context(ele: &ElementRef<'_>) -> String {
+    let mut context = match ele.attr("aria-describedby") {
+        Some(ids) => ids
+            .split_ascii_whitespace()
+            .filter_map(|id| get_element_by_id(ele, id))
+            .map(|e| content_text(&e))
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => String::new(),
+    };
+
+    if let Some(parent) = ele
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .find(|e| LINK_CONTEXT.contains(&e.value().name()))
+    {
+        context.push(' ');
+        context.push_str(&content_text(&parent));
+    }
+
+    normalize_text(&context)
+}
+
+/// the context adds information to the link text
+fn has_link_context(text: &str, context: &str) -> bool {
+    let text = normalize_link_text(text);
+    let context = normalize_link_text(context);
+
+    !context.is_empty() && context != text && context.len() > text.len()
+}
+
+/// validate generic link texts have a context that identifies the purpose of the link
+pub fn validate_link_text_context(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Vec<Validation> {
+    let phrases = get_phrases_i18n("ambiguous_link_text", &content_locale(auditor));
+    let mut validations = Vec::new();
+
+    for ele in nodes {
+        let ele = ele.0;
+
+        if ele.attr("href").is_some() {
+            let text = accessible_name(&ele);
+            let title = ele.attr("title").unwrap_or_default();
+
+            if is_ambiguous_link_text(&text, &phrases)
+                && !has_link_context(&text, &link_context(&ele))
+                && (title.trim().is_empty() || is_ambiguous_link_text(title, &phrases))
+            {
+                let message = t!(
+                    &get_message_i18n_str_raw(
+                        &Guideline::Navigable,
+                        "H77,H78,H79,H80,H81",
+                        "4",
+                        "Ambiguous"
+                    ),
+                    locale = auditor.locale,
+                    text = text
+                );
+                validations.push(Validation::new(
+                    false,
+                    "Ambiguous",
+                    vec![get_unique_selector(&ele)],
+                    message,
+                ));
+            }
+        }
+    }
+
+    validations
+}
+
+/// validate link texts identify the purpose of the link alone
+pub fn validate_link_text_only(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Vec<Validation> {
+    let phrases = get_phrases_i18n("ambiguous_link_text", &content_locale(auditor));
+    let mut validations = Vec::new();
+
+    for ele in nodes {
+        let ele = ele.0;
+
+        if ele.attr("href").is_some() {
+            let text = accessible_name(&ele);
+
+            if is_ambiguous_link_text(&text, &phrases) {
+                let message = t!(
+                    &get_message_i18n_str_raw(&Guideline::Navigable, "H30", "9", "Ambiguous"),
+                    locale = auditor.locale,
+                    text = text
+                );
+                validations.push(Validation::new(
+                    false,
+                    "Ambiguous",
+                    vec![get_unique_selector(&ele)],
+                    message,
+                ));
+            }
+        }
+    }
+
+    validations
+}
+
+/// validate links with the same text point to the same destination
+pub fn validate_link_text_destinations(nodes: &ElementNodes, locale: &str) -> Vec<Validation> {
+    let mut texts: Vec<(String, Vec<&str>, Vec<String>)> = Vec::new();
+    let mut validations = Vec::new();
+
+    for ele in nodes {
+        let ele = ele.0;
+
+        if let Some(href) = ele.value().attr("href") {
+            let text = accessible_name(&ele);
+            let key = normalize_link_text(&text);
+
+            if !key.is_empty() {
+                let href = href.trim();
+
+                match texts.iter_mut().find(|t| t.0 == key) {
+                    Some(entry) => {
+                        if !entry.1.contains(&href) {
+                            entry.1.push(href);
+                        }
+                        entry.2.push(get_unique_selector(&ele));
+                    }
+                    _ => texts.push((key, vec![href], vec![get_unique_selector(&ele)])),
+                }
+            }
+        }
+    }
+
+    for (text, hrefs, elements) in texts {
+        if hrefs.len() > 1 {
+            let message = t!(
+                &get_message_i18n_str_raw(&Guideline::Navigable, "H30", "9", "SameText"),
+                locale = locale,
+                text = text,
+                hrefs = hrefs.join(", ")
+            );
+            validations.push(Validation::new(false, "SameText", elements, message));
+        }
+    }
+
+    validations
+}
+
+/// the text decoration lines drawn on the element including the lines propagated from the ancestors
+fn text_decorations(
+    ele: &ElementRef<'_>,
+    styles: &mut ComputedStyles<'_, '_>,
+) -> TextDecorationLine {
+    let mut lines = TextDecorationLine::NONE;
+
+    for e in std::iter::once(*ele).chain(ele.ancestors().filter_map(ElementRef::wrap)) {
+        lines = lines.union(styles.style(&e).text.text_decoration_line);
+    }
+
+    lines
+}
+
+/// the style draws a visible bottom border below the text
+fn has_bottom_border(style: &ComputedValues) -> bool {
+    let border = &style.border;
+
+    !matches!(
+        border.border_bottom_style,
+        LineStyle::None | LineStyle::Hidden
+    ) && border.border_bottom_width.0.inner_px() > 0.0
+        && style.to_rgba(border.border_bottom_color).alpha > 0
+}
+
+/// the link style has a cue other than color that distinguishes it from the surrounding text
+fn has_non_color_cue(
+    style: &ComputedValues,
+    lines: TextDecorationLine,
+    text: &ComputedValues,
+    text_lines: TextDecorationLine,
+) -> bool {
+    lines.union(text_lines) != text_lines
+        || style.font.font_weight.is_bold() != text.font.font_weight.is_bold()
+        || style.font.font_size.0.px != text.font.font_size.0.px
+        || has_bottom_border(style)
+}
+
+/// the links inside a block of text that only differ from the surrounding text by color with the contrast between them
+fn color_only_links<'a>(
+    nodes: &ElementNodes<'a>,
+    styles: &mut ComputedStyles<'_, '_>,
+) -> Vec<(ElementRef<'a>, f32)> {
+    let mut links = Vec::new();
+
+    for ele in nodes {
+        let ele = ele.0;
+
+        if ele.attr("href").is_some()
+            && is_inline_text_target(&ele)
+            && styles.visibility(&ele) == Visibility::Visible
+        {
+            if let Some(parent) = ele.parent().and_then(ElementRef::wrap) {
+                let text = styles.style(&parent);
+                let text_lines = text_decorations(&parent, styles);
+                let style = styles.style(&ele);
+                let lines = text_decorations(&ele, styles);
+
+                if !has_non_color_cue(&style, lines, &text, text_lines) {
+                    let background = background_color(&parent, styles);
+                    let ratio = contrast_ratio(
+                        composite(style.color.color, background),
+                        composite(text.color.color, background),
+                    );
+
+                    links.push((ele, ratio));
+                }
+            }
+        }
+    }
+
+    links
+}
+
+/// push a validation for the link relying on color with the contrast against the surrounding text
+fn push_color_link(
+    validations: &mut Vec<Validation>,
+    ele: &ElementRef<'_>,
+    technique: &str,
+    ratio: f32,
+    locale: &str,
+) {
+    let message = t!(
+        &get_message_i18n_str_raw(&Guideline::Distinguishable, technique, "1", ""),
+        locale = locale,
+        value = format!("{:.2}", ratio)
+    );
+    validations.push(Validation::new(
+        false,
+        "",
+        vec![get_unique_selector(ele)],
+        message,
+    ));
+}
+
+/// validate links inside text do not rely on color alone with less than 3:1 contrast to the surrounding text
+pub fn validate_link_color_only(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Vec<Validation> {
+    let mut styles = ComputedStyles::new(auditor);
+    let mut validations = Vec::new();
+
+    for (ele, ratio) in color_only_links(nodes, &mut styles) {
+        if ratio < LINK_TEXT_CONTRAST {
+            push_color_link(&mut validations, &ele, "F73", ratio, auditor.locale);
+        }
+    }
+
+    validations
+}
+
+/// validate links inside text distinguished by a 3:1 contrast add a non color cue when focused
+pub fn validate_link_focus_cue(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Vec<Validation> {
+    let mut styles = ComputedStyles::new(auditor);
+    let mut validations = Vec::new();
+
+    for (ele, ratio) in color_only_links(nodes, &mut styles) {
+        if ratio >= LINK_TEXT_CONTRAST {
+            let style = styles.style(&ele);
+            let focus = styles.focus_style(&ele);
+            let parent_lines = match ele.parent().and_then(ElementRef::wrap) {
+                Some(parent) => text_decorations(&parent, &mut styles),
+                _ => TextDecorationLine::NONE,
+            };
+            let focus_lines = focus.text.text_decoration_line.union(parent_lines);
+
+            if !has_non_color_cue(
+                &focus,
+                focus_lines,
+                &style,
+                text_decorations(&ele, &mut styles),
+            ) {
+                push_color_link(&mut validations, &ele, "G183", ratio, auditor.locale);
+            }
+        }
+    }
+
+    validations
+}
@@ -0,0 +1,188 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::links::content_locale;
+use crate::engine::rules::utils::names::accessible_name;
+use crate::engine::rules::utils::nodes::{get_element_by_id, get_unique_selector, ElementNodes};
+use crate::i18n::locales::get_phrases_i18n;
+use crate::Auditor;
+use accessibility_scraper::ElementRef;
+
+/// the track kinds that caption the audio of the media
+const CAPTION_KINDS: [&str; 2] = ["captions", "subtitles"];
+/// the track kinds that describe the video of the media
+const DESCRIPTION_KINDS: [&str; 1] = ["descriptions"];
+/// the track kinds with text presented to the user in a language
+const TEXT_KINDS: [&str; 3] = ["captions", "subtitles", "descriptions"];
+/// the file extensions of audio and video that can be embedded with object or embed
+const MEDIA_EXTENSIONS: [&str; 20] = [
+    ".3gp", ".aac", ".avi", ".flac", ".flv", ".m4a", ".m4v", ".mkv", ".mov", ".mp3", ".mp4",
+    ".mpeg", ".mpg", ".oga", ".ogg", ".ogv", ".swf", ".wav", ".webm", ".wmv",
+];
+/// the mime types of the plugins that play audio or video
+const MEDIA_PLUGIN_TYPES: [&str; 5] = [
+    "application/x-shockwave-flash",
+    "application/vnd.rn-realmedia",
+    "application/x-mplayer2",
+    "application/ogg",
+    "application/vnd.apple.mpegurl",
+];
+/// the hosts of the video players embedded with object or embed
+const MEDIA_HOSTS: [&str; 4] = [
+    "youtube.com/",
+    "youtube-nocookie.com/",
+    "youtu.be/",
+    "vimeo.com/",
+];
+
+/// the kind of the track element <https://html.spec.whatwg.org/multipage/media.html#attr-track-kind>
+pub fn track_kind(ele: &ElementRef<'_>) -> &'static str {
+    match ele
+        .attr("kind")
+        .map(|kind| kind.trim().to_ascii_lowercase())
+    {
+        None => "subtitles",
+        Some(kind) => match kind.as_str() {
+            "" | "subtitles" => "subtitles",
+            "captions" => "captions",
+            "descriptions" => "descriptions",
+            "chapters" => "chapters",
+            // invalid kinds are metadata
+            _ => "metadata",
+        },
+    }
+}
+
+/// the media element has a track of one of the kinds
+pub fn has_track(ele: &ElementRef<'_>, kinds: &[&str]) -> bool {
+    ele.children()
+        .filter_map(ElementRef::wrap)
+        .any(|e| e.value().name() == "track" && kinds.contains(&track_kind(&e)))
+}
+
+/// the text mentions one of the phrases
+fn contains_phrase(text: &str, phrases: &[String]) -> bool {
+    let text = text.to_lowercase();
+
+    phrases.iter().any(|p| !p.is_empty() && text.contains(p))
+}
+
+/// the media element is described by an element or links to a transcript next to it
+pub fn has_transcript(ele: &ElementRef<'_>, phrases: &[String]) -> bool {
+    let described = ele.attr("aria-describedby").is_some_and(|ids| {
+        ids.split_ascii_whitespace().any(|id| {
+            get_element_by_id(ele, id).is_some_and(|e| e.text().any(|t| !t.trim().is_empty()))
+        })
+    });
+
+    // the links of the whole document are not close to the media
+    let container = ele
+        .parent()
+        .and_then(ElementRef::wrap)
+        .filter(|parent| !["body", "html"].contains(&parent.value().name()))
+        .unwrap_or(*ele);
+
+    described
+        || container
+            .descendants()
+            .filter_map(ElementRef::wrap)
+            .any(|e| {
+                e.value().name() == "a"
+                    && e.attr("href").is_some_and(|href| {
+                        contains_phrase(href, phrases)
+                            || contains_phrase(&accessible_name(&e), phrases)
+                    })
+            })
+}
+
+/// the media plays on load with sound
+fn is_autoplay_audible(ele: &ElementRef<'_>) -> bool {
+    ele.attr("autoplay").is_some() && ele.attr("muted").is_none()
+}
+
+/// the object or embed element loads audio or video by its type, file extension or player host
+pub fn is_embedded_media(ele: &ElementRef<'_>) -> bool {
+    let mime = ele
+        .attr("type")
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let source = ele
+        .attr("data")
+        .or_else(|| ele.attr("src"))
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let source = source.split(['?', '#']).next().unwrap_or_default();
+
+    mime.starts_with("audio/")
+        || mime.starts_with("video/")
+        || MEDIA_PLUGIN_TYPES.contains(&mime.as_str())
+        || MEDIA_EXTENSIONS.iter().any(|ext| source.ends_with(ext))
+        || MEDIA_HOSTS.iter().any(|host| source.contains(host))
+}
+
+/// validate the media elements of the nodes matching the predicate
+fn validate_media(
+    nodes: &ElementNodes,
+    id: &'static str,
+    invalid: impl Fn(&ElementRef<'_>) -> bool,
+) -> Validation {
+    let elements = nodes
+        .iter()
+        .filter(|node| invalid(&node.0))
+        .map(|node| get_unique_selector(&node.0))
+        .collect::<Vec<_>>();
+
+    Validation::new(elements.is_empty(), id, elements, Default::default())
+}
+
+/// validate the video elements have a captions or subtitles track
+pub fn validate_media_captions(nodes: &ElementNodes) -> Validation {
+    validate_media(nodes, "Captions", |ele| !has_track(ele, &CAPTION_KINDS))
+}
+
+/// validate the video elements have a descriptions track or a transcript when the transcript is allowed
+pub fn validate_media_descriptions(
+    nodes: &ElementNodes,
+    auditor: &Auditor<'_>,
+    transcript: bool,
+) -> Validation {
+    let phrases = get_phrases_i18n("transcript", &content_locale(auditor));
+
+    validate_media(nodes, "Descriptions", |ele| {
+        !has_track(ele, &DESCRIPTION_KINDS) && (!transcript || !has_transcript(ele, &phrases))
+    })
+}
+
+/// validate the audio elements have a transcript
+pub fn validate_media_transcript(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Validation {
+    let phrases = get_phrases_i18n("transcript", &content_locale(auditor));
+
+    validate_media(nodes, "Transcript", |ele| !has_transcript(ele, &phrases))
+}
+
+/// validate media playing sound on load can be paused with the controls
+pub fn validate_media_autoplay(nodes: &ElementNodes) -> Validation {
+    validate_media(nodes, "", |ele| {
+        is_autoplay_audible(ele) && ele.attr("controls").is_none()
+    })
+}
+
+/// validate media playing sound on load with the controls lasts less than three seconds or can be paused
+pub fn validate_media_autoplay_controls(nodes: &ElementNodes) -> Validation {
+    validate_media(nodes, "", |ele| {
+        is_autoplay_audible(ele) && ele.attr("controls").is_some()
+    })
+}
+
+/// validate the text tracks have a language and a label
+pub fn validate_track_attributes(nodes: &ElementNodes, attr: &str, id: &'static str) -> Validation {
+    validate_media(nodes, id, |ele| {
+        TEXT_KINDS.contains(&track_kind(ele))
+            && ele.attr(attr).unwrap_or_default().trim().is_empty()
+    })
+}
+
+/// validate the legacy object and embed elements that may contain media
+pub fn validate_embedded_media(nodes: &ElementNodes) -> Validation {
+    validate_media(nodes, "Embedded", is_embedded_media)
+}
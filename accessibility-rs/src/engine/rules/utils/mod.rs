@@ -1,2 +1,46 @@
+/// abbreviations and acronyms without an expanded form
+pub mod abbreviations;
+/// quality of the alt text of images
+pub mod alt_text;
+/// autofill detail tokens for autocomplete
+pub mod autocomplete;
+/// submit buttons of forms and the names of buttons
+pub mod buttons;
+/// text and non-text contrast with the composited backgrounds
+pub mod contrast;
+/// focus indicator styles
+pub mod focus;
+/// radio button and checkbox groups
+pub mod grouping;
+/// keyboard operation and focus order
+pub mod keyboard;
+/// BCP 47 language tags of the lang attributes
+pub mod language;
+/// link purpose from the link text and context
+pub mod links;
+/// captions, descriptions and autoplay of media elements
+pub mod media;
+/// css animations, transitions and the reduced motion preference
+pub mod motion;
+/// accessible name computation
+pub mod names;
 /// utilities for node extracting
 pub mod nodes;
+/// obsolete presentational elements, attributes and layout tables
+pub mod presentation;
+/// readability of the main content text
+pub mod readability;
+/// instructions relying on sensory characteristics
+pub mod sensory;
+/// computed styles inherited through the ancestors
+pub mod styles;
+/// text alternatives of inline svg images
+pub mod svg;
+/// pointer target sizes from the layout
+pub mod target;
+/// document title quality and uniqueness
+pub mod title;
+/// meta viewport zoom restrictions
+pub mod viewport;
+/// the visibility of the elements on screen and to assistive technology
+pub mod visibility;
@@ -0,0 +1,95 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::descendant_elements;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::utils::styles::ComputedStyles;
+use crate::Auditor;
+use accessibility_tree::style::cascade::declares_motion;
+use accessibility_tree::style::values::AnimationName;
+use accessibility_tree::style::ComputedValues;
+
+/// the style plays a css animation
+pub fn is_animated(style: &ComputedValues) -> bool {
+    let animation = &style.animation;
+
+    animation.animation_name == AnimationName::Named && !animation.animation_duration.is_zero()
+}
+
+/// the style transitions a property that moves the element
+pub fn is_transitioned(style: &ComputedValues) -> bool {
+    let animation = &style.animation;
+
+    animation.transition_property.is_motion() && !animation.transition_duration.is_zero()
+}
+
+/// validate the rendered elements do not play css animations that repeat forever
+pub fn validate_infinite_animations(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Validation {
+    let mut styles = ComputedStyles::new(auditor);
+    let mut elements = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if !declares_motion(&auditor.author, &ele) {
+                continue;
+            }
+            let style = styles.style(&ele);
+
+            if is_animated(&style)
+                && style.animation.animation_iteration_count.is_infinite()
+                && styles.visibility(&ele).is_rendered()
+            {
+                elements.push(get_unique_selector(&ele));
+            }
+        }
+    }
+
+    Validation::new(
+        elements.is_empty(),
+        "Infinite",
+        elements,
+        Default::default(),
+    )
+}
+
+/// validate the animations and transitions of the rendered elements are removed when the user prefers reduced motion
+pub fn validate_reduced_motion(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Vec<Validation> {
+    let mut styles = ComputedStyles::new(auditor);
+    let mut animations = Vec::new();
+    let mut transitions = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if !declares_motion(&auditor.author, &ele) {
+                continue;
+            }
+            let style = styles.style(&ele);
+            let animated = is_animated(&style);
+            let transitioned = is_transitioned(&style);
+
+            if (animated || transitioned) && styles.visibility(&ele).is_rendered() {
+                let reduced = styles.reduced_motion_style(&ele);
+
+                if animated && is_animated(&reduced) {
+                    animations.push(get_unique_selector(&ele));
+                }
+                if transitioned && is_transitioned(&reduced) {
+                    transitions.push(get_unique_selector(&ele));
+                }
+            }
+        }
+    }
+
+    vec![
+        Validation::new(
+            animations.is_empty(),
+            "Animation",
+            animations,
+            Default::default(),
+        ),
+        Validation::new(
+            transitions.is_empty(),
+            "Transition",
+            transitions,
+            Default::default(),
+        ),
+    ]
+}
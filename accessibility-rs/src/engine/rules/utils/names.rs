@@ -0,0 +1,135 @@
+use crate::engine::rules::utils::nodes::get_element_by_id;
+use accessibility_scraper::ElementRef;
+
+/// elements that get their name from their content <https://www.w3.org/TR/wai-aria-1.2/#namefromcontent>
+const NAME_FROM_CONTENT: [&str; 14] = [
+    "a", "button", "h1", "h2", "h3", "h4", "h5", "h6", "summary", "legend", "label", "th", "td",
+    "option",
+];
+
+/// roles that get their name from their content
+const NAME_FROM_CONTENT_ROLES: [&str; 14] = [
+    "button",
+    "cell",
+    "checkbox",
+    "columnheader",
+    "gridcell",
+    "heading",
+    "link",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "rowheader",
+    "tab",
+];
+
+/// collapse the white space of the text
+pub fn normalize_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// the element is hidden from assistive technology by markup
+pub fn is_hidden_from_at(ele: &ElementRef<'_>) -> bool {
+    ele.value().attr("hidden").is_some()
+        || ele.attr("aria-hidden").unwrap_or_default().trim() == "true"
+}
+
+/// the element can get its name from its content
+pub fn name_from_content(ele: &ElementRef<'_>) -> bool {
+    match ele.attr("role") {
+        Some(role) => NAME_FROM_CONTENT_ROLES.contains(&role.trim()),
+        _ => NAME_FROM_CONTENT.contains(&ele.value().name()),
+    }
+}
+
+/// the text alternative of the element from its own attributes
+fn native_name(ele: &ElementRef<'_>) -> String {
+    let name = ele.value().name();
+
+    match name {
+        "img" | "area" => ele.attr("alt").unwrap_or_default().into(),
+        "input" => match ele
+            .attr("type")
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "image" => ele.attr("alt").unwrap_or_default().into(),
+            "submit" | "reset" | "button" => ele.attr("value").unwrap_or_default().into(),
+            _ => Default::default(),
+        },
+        "svg" => ele
+            .children()
+            .filter_map(ElementRef::wrap)
+            .find(|e| e.value().name() == "title")
+            .map(|e| e.text().collect::<String>())
+            .unwrap_or_default(),
+        _ => Default::default(),
+    }
+}
+
+/// the text of the content of the element with text alternatives of embedded elements
+pub fn content_text(ele: &ElementRef<'_>) -> String {
+    let mut text = String::new();
+
+    for child in ele.children() {
+        if let Some(t) = child.value().as_text() {
+            text.push_str(t);
+        } else if let Some(element) = ElementRef::wrap(child) {
+            if !is_hidden_from_at(&element) {
+                text.push(' ');
+                text.push_str(&text_alternative(&element, true));
+                text.push(' ');
+            }
+        }
+    }
+
+    normalize_text(&text)
+}
+
+/// compute the text alternative of the element <https://www.w3.org/TR/accname-1.2/>
+fn text_alternative(ele: &ElementRef<'_>, traversal: bool) -> String {
+    if !traversal {
+        if let Some(ids) = ele.attr("aria-labelledby") {
+            let name = ids
+                .split_ascii_whitespace()
+                .filter_map(|id| get_element_by_id(ele, id))
+                .map(|e| text_alternative(&e, true))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            if !name.trim().is_empty() {
+                return normalize_text(&name);
+            }
+        }
+    }
+
+    let label = ele.attr("aria-label").unwrap_or_default();
+
+    if !label.trim().is_empty() {
+        return normalize_text(label);
+    }
+
+    let native = native_name(ele);
+
+    if !native.trim().is_empty() {
+        return normalize_text(&native);
+    }
+
+    if traversal || name_from_content(ele) {
+        let content = content_text(ele);
+
+        if !content.is_empty() {
+            return content;
+        }
+    }
+
+    normalize_text(ele.attr("title").unwrap_or_default())
+}
+
+/// the accessible name of the element
+pub fn accessible_name(ele: &ElementRef<'_>) -> String {
+    text_alternative(ele, false)
+}
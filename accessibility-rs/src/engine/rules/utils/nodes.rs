@@ -3,7 +3,8 @@ use accessibility_scraper::ElementRef;
 use accessibility_scraper::Selector;
 use selectors::Element;
 
-type ElementNodes<'a> = Vec<(ElementRef<'a>, Option<taffy::NodeId>)>;
+/// the elements and layout nodes a rule validates
+pub type ElementNodes<'a> = Vec<(ElementRef<'a>, Option<taffy::NodeId>)>;
 
 /// a valid alt attribute for image
 pub fn has_alt(ele: ElementRef<'_>) -> bool {
@@ -82,51 +83,50 @@ pub fn single_selector(ele: &ElementRef<'_>, node_selector: &str) -> bool {
     }
 }
 
-/// get the unique selector for an element
-pub fn get_unique_selector(ele: &ElementRef<'_>) -> String {
+/// the selector of the element when it is unique without its ancestors
+fn own_unique_selector(ele: &ElementRef<'_>) -> Option<String> {
+    let node_name = ele.value().name();
+
     if ele.has_attribute("id") {
-        "#".to_string() + ele.attr("id").unwrap_or_default()
+        Some("#".to_string() + ele.attr("id").unwrap_or_default())
+    } else if node_name == "body" || node_name == "html" {
+        Some(node_name.to_string())
     } else {
-        let mut selector = String::new();
-        let node_name = ele.value().name();
+        if ele.has_attribute("class") {
+            let node_selector = node_name.to_string() + &ele.value().local_name.to_string();
+            if single_selector(ele, &node_selector) {
+                return Some(node_selector);
+            }
+        }
 
-        if node_name == "body" || node_name == "html" {
-            node_name.to_string()
+        if single_selector(ele, node_name) {
+            Some(node_name.to_string())
         } else {
-            let node_name = ele.value().name();
+            None
+        }
+    }
+}
 
-            if selector.is_empty() && ele.has_attribute("class") {
-                let node_selector = node_name.to_string() + &ele.value().local_name.to_string();
-                let only_selector = single_selector(ele, &node_selector);
-                if only_selector {
-                    selector = node_selector;
-                }
-            }
+/// get the unique selector for an element
+pub fn get_unique_selector(ele: &ElementRef<'_>) -> String {
+    // walk up without recursion since malformed markup can nest thousands of elements
+    let mut positions = Vec::new();
+    let mut current = *ele;
 
-            if !selector.is_empty() {
-                selector
-            } else {
-                if single_selector(ele, &node_name) {
-                    node_name.to_string()
-                } else {
-                    let pos = get_sibling_position(ele);
-
-                    let s = match ele.parent_element() {
-                        Some(p) => {
-                            if selector.is_empty() {
-                                get_unique_selector(&p)
-                            } else {
-                                selector
-                            }
-                        }
-                        _ => ele.value().name().to_string(),
-                    };
-
-                    s + ">:nth-child(" + &pos.to_string() + ")"
-                }
-            }
+    let selector = loop {
+        if let Some(selector) = own_unique_selector(&current) {
+            break selector;
         }
-    }
+        positions.push(get_sibling_position(&current));
+        match current.parent_element() {
+            Some(p) => current = p,
+            _ => break current.value().name().to_string(),
+        }
+    };
+
+    positions.iter().rev().fold(selector, |s, pos| {
+        s + ">:nth-child(" + &pos.to_string() + ")"
+    })
 }
 
 /// get sibling position of element
@@ -163,3 +163,25 @@ pub fn validate_missing_attr(
 
     Validation::new(valid, id, elements, Default::default())
 }
+
+/// get the element in the document with the id
+pub fn get_element_by_id<'a>(ele: &ElementRef<'a>, id: &str) -> Option<ElementRef<'a>> {
+    ele.tree()
+        .nodes()
+        .filter_map(ElementRef::wrap)
+        .find(|e| e.value().id() == Some(id))
+}
+
+/// element has a non empty aria-label or an aria-labelledby pointing to elements with text
+pub fn has_aria_label(ele: &ElementRef<'_>) -> bool {
+    if !ele.attr("aria-label").unwrap_or_default().trim().is_empty() {
+        true
+    } else {
+        match ele.attr("aria-labelledby") {
+            Some(ids) => ids.split_ascii_whitespace().any(|id| {
+                get_element_by_id(ele, id).is_some_and(|e| e.text().any(|t| !t.trim().is_empty()))
+            }),
+            _ => false,
+        }
+    }
+}
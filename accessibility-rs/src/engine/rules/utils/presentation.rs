@@ -0,0 +1,126 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::descendant_elements;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use accessibility_scraper::ElementRef;
+
+/// the obsolete presentational attributes and the message id of their css equivalent
+const PRESENTATIONAL_ATTRIBUTES: [(&str, &str); 9] = [
+    ("align", "AlignAttr"),
+    ("valign", "ValignAttr"),
+    ("bgcolor", "BgcolorAttr"),
+    ("background", "BackgroundAttr"),
+    ("cellpadding", "CellpaddingAttr"),
+    ("cellspacing", "CellspacingAttr"),
+    ("nowrap", "NowrapAttr"),
+    ("hspace", "SpaceAttr"),
+    ("vspace", "SpaceAttr"),
+];
+/// the obsolete colour attributes of the body
+const BODY_COLOR_ATTRIBUTES: [&str; 4] = ["text", "link", "vlink", "alink"];
+/// the elements that structure content and do not belong in a data cell
+const LAYOUT_CONTENT: [&str; 13] = [
+    "table", "h1", "h2", "h3", "h4", "h5", "h6", "form", "nav", "main", "header", "footer", "aside",
+];
+/// the elements and attributes that make a table a data table
+const DATA_TABLE_CONTENT: [&str; 3] = ["th", "caption", "thead"];
+
+/// validate the obsolete presentational elements of the nodes
+pub fn validate_presentational_element(nodes: &ElementNodes, id: &'static str) -> Validation {
+    let elements = nodes
+        .iter()
+        .map(|node| get_unique_selector(&node.0))
+        .collect::<Vec<_>>();
+
+    Validation::new(elements.is_empty(), id, elements, Default::default())
+}
+
+/// the message ids of the obsolete presentational attributes of the element
+fn presentational_attributes(ele: &ElementRef<'_>) -> Vec<&'static str> {
+    let mut ids = PRESENTATIONAL_ATTRIBUTES
+        .iter()
+        .filter(|(attr, _)| ele.attr(attr).is_some())
+        .map(|(_, id)| *id)
+        .collect::<Vec<_>>();
+
+    if ele.value().name() == "body" && BODY_COLOR_ATTRIBUTES.iter().any(|a| ele.attr(a).is_some()) {
+        ids.push("ColorAttr");
+    }
+
+    ids
+}
+
+/// validate the elements do not use obsolete presentational attributes
+pub fn validate_presentational_attributes(nodes: &ElementNodes) -> Vec<Validation> {
+    let mut validations: Vec<(&'static str, Vec<String>)> = Vec::new();
+
+    for id in PRESENTATIONAL_ATTRIBUTES
+        .iter()
+        .map(|(_, id)| *id)
+        .chain(std::iter::once("ColorAttr"))
+    {
+        if !validations.iter().any(|(v, _)| *v == id) {
+            validations.push((id, Vec::new()));
+        }
+    }
+
+    for node in nodes {
+        for ele in std::iter::once(node.0).chain(descendant_elements(&node.0)) {
+            let ids = presentational_attributes(&ele);
+
+            if ids.is_empty() {
+                continue;
+            }
+
+            let selector = get_unique_selector(&ele);
+
+            for (id, elements) in validations.iter_mut() {
+                if ids.contains(id) && !elements.contains(&selector) {
+                    elements.push(selector.clone());
+                }
+            }
+        }
+    }
+
+    validations
+        .into_iter()
+        .map(|(id, elements)| {
+            Validation::new(elements.is_empty(), id, elements, Default::default())
+        })
+        .collect()
+}
+
+/// the table is marked as a data table by headers, a caption or a summary
+fn is_data_table(ele: &ElementRef<'_>) -> bool {
+    !ele.attr("summary").unwrap_or_default().trim().is_empty()
+        || descendant_elements(ele).any(|e| {
+            DATA_TABLE_CONTENT.contains(&e.value().name())
+                || e.attr("headers").is_some()
+                || e.attr("scope").is_some()
+        })
+}
+
+/// the table arranges the structure of the page rather than data
+pub fn is_layout_table(ele: &ElementRef<'_>) -> bool {
+    let role = ele.attr("role").unwrap_or_default().trim();
+
+    role != "presentation"
+        && role != "none"
+        && !is_data_table(ele)
+        && descendant_elements(ele).any(|e| LAYOUT_CONTENT.contains(&e.value().name()))
+}
+
+/// validate the tables are not used for the layout of the page
+pub fn validate_layout_tables(nodes: &ElementNodes) -> Validation {
+    let elements = nodes
+        .iter()
+        .filter(|node| is_layout_table(&node.0))
+        .map(|node| get_unique_selector(&node.0))
+        .collect::<Vec<_>>();
+
+    Validation::new(
+        elements.is_empty(),
+        "LayoutTable",
+        elements,
+        Default::default(),
+    )
+}
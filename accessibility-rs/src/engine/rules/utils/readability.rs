@@ -0,0 +1,208 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::{descendant_elements, is_hidden_subtree};
+use crate::engine::rules::utils::links::content_locale;
+use crate::engine::rules::utils::names::normalize_text;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::wcag_base::Guideline;
+use crate::i18n::locales::{get_locale_from_lang, get_message_i18n_str_raw};
+use crate::Auditor;
+use accessibility_scraper::ElementRef;
+
+/// the locales the syllable counting and grade formula support
+const READABILITY_LOCALES: [&str; 1] = ["en"];
+/// the sections of the page around the main content
+const EXCLUDED_SECTIONS: [&str; 3] = ["nav", "header", "footer"];
+/// the roles of the sections of the page around the main content
+const EXCLUDED_ROLES: [&str; 3] = ["navigation", "banner", "contentinfo"];
+/// the fewest words of a paragraph that give a stable grade
+const MIN_PARAGRAPH_WORDS: usize = 30;
+/// the fewest words of the main content that give a stable grade
+const MIN_PAGE_WORDS: usize = 100;
+
+/// the counts of the text used by the readability formulas
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Readability {
+    /// the amount of words
+    pub words: usize,
+    /// the amount of sentences
+    pub sentences: usize,
+    /// the amount of syllables of the words
+    pub syllables: usize,
+    /// the amount of letters of the words
+    pub letters: usize,
+}
+
+impl Readability {
+    /// count the words, sentences and syllables of english text
+    pub fn new(text: &str) -> Self {
+        let mut readability = Readability::default();
+        let mut sentence_words = 0;
+
+        for token in text.split_whitespace() {
+            let word = token.trim_matches(|c: char| !c.is_alphanumeric());
+
+            if word.chars().any(char::is_alphabetic) {
+                readability.words += 1;
+                readability.syllables += syllables(word);
+                readability.letters += word.chars().filter(|c| c.is_alphabetic()).count();
+                sentence_words += 1;
+            }
+
+            if sentence_words > 0 && token.ends_with(['.', '!', '?']) {
+                readability.sentences += 1;
+                sentence_words = 0;
+            }
+        }
+
+        // the last sentence may not end with punctuation
+        if sentence_words > 0 {
+            readability.sentences += 1;
+        }
+
+        readability
+    }
+
+    /// combine the counts of both texts
+    pub fn add(&mut self, other: &Self) {
+        self.words += other.words;
+        self.sentences += other.sentences;
+        self.syllables += other.syllables;
+        self.letters += other.letters;
+    }
+
+    /// the average words of a sentence
+    pub fn words_per_sentence(&self) -> f32 {
+        self.words as f32 / self.sentences.max(1) as f32
+    }
+
+    /// the average letters of a word
+    pub fn letters_per_word(&self) -> f32 {
+        self.letters as f32 / self.words.max(1) as f32
+    }
+
+    /// the Flesch-Kincaid grade level <https://en.wikipedia.org/wiki/Flesch%E2%80%93Kincaid_readability_tests>
+    pub fn grade(&self) -> f32 {
+        let syllables_per_word = self.syllables as f32 / self.words.max(1) as f32;
+
+        0.39 * self.words_per_sentence() + 11.8 * syllables_per_word - 15.59
+    }
+}
+
+/// estimate the syllables of an english word from its vowel groups
+pub fn syllables(word: &str) -> usize {
+    let word = word.to_lowercase();
+    let mut count = 0;
+    let mut previous_vowel = false;
+
+    for c in word.chars() {
+        let vowel = "aeiouy".contains(c);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    // the final e is silent except in endings such as table
+    if count > 1 && word.ends_with('e') && !word.ends_with("le") {
+        count -= 1;
+    }
+
+    count.max(1)
+}
+
+/// the locale of the element content from the closest lang attribute
+fn element_locale(ele: &ElementRef<'_>, document_locale: &str) -> String {
+    std::iter::once(*ele)
+        .chain(ele.ancestors().filter_map(ElementRef::wrap))
+        .find_map(|e| {
+            e.attr("lang")
+                .filter(|lang| !lang.trim().is_empty())
+                .map(get_locale_from_lang)
+        })
+        .unwrap_or_else(|| document_locale.into())
+}
+
+/// the element is inside the navigation, header or footer of the page
+fn is_outside_main_content(ele: &ElementRef<'_>) -> bool {
+    ele.ancestors().filter_map(ElementRef::wrap).any(|e| {
+        EXCLUDED_SECTIONS.contains(&e.value().name())
+            || e.attr("role")
+                .is_some_and(|role| EXCLUDED_ROLES.contains(&role.trim()))
+    })
+}
+
+/// the main landmark of the page or the body
+fn main_content<'a>(body: &ElementRef<'a>) -> ElementRef<'a> {
+    descendant_elements(body)
+        .find(|e| {
+            e.value().name() == "main" || e.attr("role").is_some_and(|role| role.trim() == "main")
+        })
+        .unwrap_or(*body)
+}
+
+/// validate the paragraphs and the main content read below the configured grade level
+pub fn validate_readability(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Vec<Validation> {
+    let max_grade = match auditor.options.readability_grade {
+        Some(grade) => grade,
+        _ => return Default::default(),
+    };
+    let locale = auditor.locale;
+    let document_locale = content_locale(auditor);
+    let mut paragraphs = Vec::new();
+    let mut validations = Vec::new();
+
+    for node in nodes {
+        let main = main_content(&node.0);
+        let mut page = Readability::default();
+
+        for ele in descendant_elements(&main) {
+            if ele.value().name() != "p"
+                || !READABILITY_LOCALES.contains(&element_locale(&ele, &document_locale).as_str())
+                || is_outside_main_content(&ele)
+                || is_hidden_subtree(&ele)
+            {
+                continue;
+            }
+
+            let text = normalize_text(&ele.text().collect::<String>());
+            let readability = Readability::new(&text);
+
+            if readability.words >= MIN_PARAGRAPH_WORDS && readability.grade() > max_grade {
+                paragraphs.push(get_unique_selector(&ele));
+            }
+
+            page.add(&readability);
+        }
+
+        if page.words >= MIN_PAGE_WORDS && page.grade() > max_grade {
+            let message = t!(
+                &get_message_i18n_str_raw(&Guideline::Readable, "G153", "5", "Page"),
+                locale = locale,
+                grade = format!("{:.1}", page.grade()),
+                maxGrade = max_grade.to_string(),
+                sentenceLength = format!("{:.1}", page.words_per_sentence()),
+                wordLength = format!("{:.1}", page.letters_per_word())
+            );
+            validations.push(Validation::new(
+                false,
+                "Page",
+                vec![get_unique_selector(&main)],
+                message,
+            ));
+        }
+    }
+
+    let message = t!(
+        &get_message_i18n_str_raw(&Guideline::Readable, "G153", "5", "Paragraph"),
+        locale = locale,
+        maxGrade = max_grade.to_string()
+    );
+    validations.push(Validation::new(
+        paragraphs.is_empty(),
+        "Paragraph",
+        paragraphs,
+        message,
+    ));
+
+    validations
+}
@@ -0,0 +1,92 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::is_hidden_subtree;
+use crate::engine::rules::utils::links::content_locale;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::wcag_base::Guideline;
+use crate::i18n::locales::{get_message_i18n_str_raw, get_phrases_i18n};
+use crate::Auditor;
+use accessibility_scraper::ElementRef;
+
+/// the elements with text that is not read as content
+const SKIPPED_ELEMENTS: [&str; 4] = ["script", "style", "noscript", "template"];
+/// the first code point of the scripts written without spaces between words
+const UNSPACED_SCRIPTS: char = '\u{2E80}';
+
+/// the character joins the phrase to a longer word of a script written with spaces
+fn is_word_char(c: Option<char>) -> bool {
+    c.is_some_and(|c| c.is_alphanumeric() && c < UNSPACED_SCRIPTS)
+}
+
+/// the lowercase text contains the phrase as whole words
+pub fn contains_phrase(text: &str, phrase: &str) -> bool {
+    text.match_indices(phrase).any(|(i, _)| {
+        !is_word_char(text[..i].chars().next_back())
+            && !is_word_char(text[i + phrase.len()..].chars().next())
+    })
+}
+
+/// the phrases of the text that describe content by its shape, colour, size, location or sound
+pub fn sensory_phrases<'a>(text: &str, phrases: &'a [String]) -> Vec<&'a str> {
+    let text = text.to_lowercase();
+
+    phrases
+        .iter()
+        .filter(|p| contains_phrase(&text, p))
+        .map(String::as_str)
+        .collect()
+}
+
+/// validate the instructions of the text do not rely on sensory characteristics alone
+pub fn validate_sensory_characteristics(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Validation {
+    let phrases = get_phrases_i18n("sensory", &content_locale(auditor));
+    let mut found: Vec<&str> = Vec::new();
+    let mut parents: Vec<ElementRef<'_>> = Vec::new();
+
+    for node in nodes {
+        for child in node.0.descendants() {
+            let text = match child.value().as_text() {
+                Some(text) => text,
+                _ => continue,
+            };
+            let matches = sensory_phrases(text, &phrases);
+
+            if matches.is_empty() {
+                continue;
+            }
+
+            let parent = match child.parent().and_then(ElementRef::wrap) {
+                Some(parent) => parent,
+                _ => continue,
+            };
+            let skipped = std::iter::once(parent)
+                .chain(parent.ancestors().filter_map(ElementRef::wrap))
+                .any(|e| SKIPPED_ELEMENTS.contains(&e.value().name()));
+
+            if skipped || is_hidden_subtree(&parent) {
+                continue;
+            }
+
+            for phrase in matches {
+                if !found.contains(&phrase) {
+                    found.push(phrase);
+                }
+            }
+            if !parents.iter().any(|p| p.id() == parent.id()) {
+                parents.push(parent);
+            }
+        }
+    }
+
+    let message = if found.is_empty() {
+        Default::default()
+    } else {
+        t!(
+            &get_message_i18n_str_raw(&Guideline::Adaptable, "G96", "3", "Sensory"),
+            locale = auditor.locale,
+            phrases = found.join("\", \"")
+        )
+    };
+    let elements = parents.iter().map(get_unique_selector).collect::<Vec<_>>();
+
+    Validation::new(found.is_empty(), "Sensory", elements, message)
+}
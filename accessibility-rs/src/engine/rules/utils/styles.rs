@@ -0,0 +1,122 @@
+use crate::engine::rules::utils::visibility::{element_visibility, subtree_visibility, Visibility};
+use crate::Auditor;
+use accessibility_scraper::ElementRef;
+use accessibility_tree::style::cascade::{
+    _style_for_element, _style_for_element_state, MatchState,
+};
+use accessibility_tree::style::ComputedValues;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// computed styles of the elements inherited through all of the ancestors
+pub struct ComputedStyles<'a, 'b> {
+    /// the auditor with the author styles
+    auditor: &'a Auditor<'b>,
+    /// the styles computed so far
+    styles: HashMap<ego_tree::NodeId, Arc<ComputedValues>>,
+    /// the visibility passed to the descendants of the elements resolved so far
+    subtrees: HashMap<ego_tree::NodeId, Visibility>,
+}
+
+impl<'a, 'b> ComputedStyles<'a, 'b> {
+    /// a new style cache for the auditor
+    pub fn new(auditor: &'a Auditor<'b>) -> Self {
+        Self {
+            auditor,
+            styles: HashMap::new(),
+            subtrees: HashMap::new(),
+        }
+    }
+
+    /// the computed style of the element
+    pub fn style(&mut self, ele: &ElementRef<'_>) -> Arc<ComputedValues> {
+        let mut pending = Vec::new();
+        let mut current = Some(*ele);
+        let mut parent_style: Option<Arc<ComputedValues>> = None;
+
+        // compute the missing styles from the closest cached ancestor down to the element
+        while let Some(e) = current {
+            if let Some(style) = self.styles.get(&e.id()) {
+                parent_style = Some(style.clone());
+                break;
+            }
+            pending.push(e);
+            current = e.parent().and_then(ElementRef::wrap);
+        }
+
+        for e in pending.iter().rev() {
+            let style = _style_for_element(
+                &self.auditor.author,
+                self.auditor.document,
+                e,
+                parent_style.as_deref(),
+            );
+            self.styles.insert(e.id(), style.clone());
+            parent_style = Some(style);
+        }
+
+        parent_style.unwrap_or_else(|| ComputedValues::anonymous_inheriting_from(None))
+    }
+
+    /// the computed style of the element in the state inheriting from the cached style of the parent
+    fn state_style(&mut self, ele: &ElementRef<'_>, state: MatchState) -> Arc<ComputedValues> {
+        let parent = ele
+            .parent()
+            .and_then(ElementRef::wrap)
+            .map(|parent| self.style(&parent));
+
+        _style_for_element_state(
+            &self.auditor.author,
+            self.auditor.document,
+            ele,
+            parent.as_deref(),
+            state,
+        )
+    }
+
+    /// the computed style of the element in the focus state
+    pub fn focus_style(&mut self, ele: &ElementRef<'_>) -> Arc<ComputedValues> {
+        self.state_style(
+            ele,
+            MatchState {
+                focus: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// the computed style of the element when the user prefers reduced motion
+    pub fn reduced_motion_style(&mut self, ele: &ElementRef<'_>) -> Arc<ComputedValues> {
+        self.state_style(
+            ele,
+            MatchState {
+                reduced_motion: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// the visibility of the element resolved through all of the ancestors
+    pub fn visibility(&mut self, ele: &ElementRef<'_>) -> Visibility {
+        let mut pending = Vec::new();
+        let mut current = Some(*ele);
+        let mut subtree = Visibility::Visible;
+
+        while let Some(e) = current {
+            if let Some(visibility) = self.subtrees.get(&e.id()) {
+                subtree = *visibility;
+                break;
+            }
+            pending.push(e);
+            current = e.parent().and_then(ElementRef::wrap);
+        }
+
+        for e in pending.iter().rev() {
+            let style = self.style(e);
+            subtree = subtree_visibility(subtree, e, &style, self.auditor.bounds.get(&e.id()));
+            self.subtrees.insert(e.id(), subtree);
+        }
+
+        element_visibility(subtree, &self.style(ele))
+    }
+}
@@ -0,0 +1,86 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::is_hidden_subtree;
+use crate::engine::rules::utils::names::accessible_name;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use accessibility_scraper::ElementRef;
+
+/// the roles that remove the svg from the accessibility tree
+const PRESENTATION_ROLES: [&str; 2] = ["presentation", "none"];
+/// the roles of the controls named by their content
+const CONTROL_ROLES: [&str; 2] = ["link", "button"];
+
+/// the role of the element
+fn role(ele: &ElementRef<'_>) -> String {
+    ele.attr("role")
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// the svg is an image exposed to assistive technology
+pub fn is_svg_image(ele: &ElementRef<'_>) -> bool {
+    role(ele) == "img"
+}
+
+/// the svg is marked as decorative
+pub fn is_svg_decorative(ele: &ElementRef<'_>) -> bool {
+    PRESENTATION_ROLES.contains(&role(ele).as_str())
+}
+
+/// the link or button containing the element
+fn closest_control<'a>(ele: &ElementRef<'a>) -> Option<ElementRef<'a>> {
+    ele.ancestors().filter_map(ElementRef::wrap).find(|e| {
+        (e.value().name() == "a" && e.attr("href").is_some())
+            || e.value().name() == "button"
+            || CONTROL_ROLES.contains(&role(e).as_str())
+    })
+}
+
+/// the svg is inside another svg
+fn is_nested_svg(ele: &ElementRef<'_>) -> bool {
+    ele.ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|e| e.value().name() == "svg")
+}
+
+/// validate the svg elements matching the predicate
+fn validate_svgs(
+    nodes: &ElementNodes,
+    id: &'static str,
+    invalid: impl Fn(&ElementRef<'_>) -> bool,
+) -> Validation {
+    let elements = nodes
+        .iter()
+        .filter(|node| !is_nested_svg(&node.0) && invalid(&node.0))
+        .map(|node| get_unique_selector(&node.0))
+        .collect::<Vec<_>>();
+
+    Validation::new(elements.is_empty(), id, elements, Default::default())
+}
+
+/// validate the svg images have a name from a title element, aria-label or aria-labelledby
+pub fn validate_svg_name(nodes: &ElementNodes) -> Validation {
+    validate_svgs(nodes, "Svg.NoName", |ele| {
+        is_svg_image(ele) && !is_hidden_subtree(ele) && accessible_name(ele).is_empty()
+    })
+}
+
+/// validate the links and buttons with only an svg as content have a name
+pub fn validate_svg_control_name(nodes: &ElementNodes) -> Validation {
+    validate_svgs(nodes, "Svg.Control", |ele| {
+        closest_control(ele).is_some_and(|control| {
+            !is_hidden_subtree(&control) && accessible_name(&control).is_empty()
+        })
+    })
+}
+
+/// validate the svg elements without a role or a name are hidden from assistive technology
+pub fn validate_svg_decorative(nodes: &ElementNodes) -> Validation {
+    validate_svgs(nodes, "Svg.Decorative", |ele| {
+        !is_svg_image(ele)
+            && !is_svg_decorative(ele)
+            && !is_hidden_subtree(ele)
+            && closest_control(ele).is_none()
+            && accessible_name(ele).is_empty()
+    })
+}
@@ -0,0 +1,168 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::keyboard::{
+    descendant_elements, has_interactive_role, is_disabled, is_hidden_subtree,
+    is_natively_focusable,
+};
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::utils::styles::ComputedStyles;
+use crate::engine::rules::wcag_base::Guideline;
+use crate::i18n::locales::get_message_i18n_str_raw;
+use crate::Auditor;
+use accessibility_scraper::ElementRef;
+use taffy::Layout;
+
+/// the minimum target size of 2.5.8 in css pixels
+pub const TARGET_SIZE_MINIMUM: f32 = 24.0;
+/// the enhanced target size of 2.5.5 in css pixels
+pub const TARGET_SIZE_ENHANCED: f32 = 44.0;
+
+/// a pointer target with the layout relative to the document
+struct Target<'a> {
+    /// the target element
+    element: ElementRef<'a>,
+    /// the layout of the target
+    layout: Layout,
+}
+
+impl Target<'_> {
+    /// the center of the target
+    fn center(&self) -> (f32, f32) {
+        (
+            self.layout.location.x + self.layout.size.width / 2.0,
+            self.layout.location.y + self.layout.size.height / 2.0,
+        )
+    }
+
+    /// the target is smaller than the size in any direction
+    fn undersized(&self, size: f32) -> bool {
+        self.layout.size.width < size || self.layout.size.height < size
+    }
+
+    /// the distance from the point to the closest edge of the target
+    fn distance(&self, (x, y): (f32, f32)) -> f32 {
+        let left = self.layout.location.x;
+        let top = self.layout.location.y;
+        let dx = (left - x).max(x - (left + self.layout.size.width)).max(0.0);
+        let dy = (top - y).max(y - (top + self.layout.size.height)).max(0.0);
+
+        (dx * dx + dy * dy).sqrt()
+    }
+}
+
+/// the element is a link inside a sentence of text
+pub fn is_inline_text_target(ele: &ElementRef<'_>) -> bool {
+    ele.value().name() == "a"
+        && ele.prev_siblings().chain(ele.next_siblings()).any(|node| {
+            node.value()
+                .as_text()
+                .is_some_and(|text| !text.trim().is_empty())
+        })
+}
+
+/// the element is a pointer target
+fn is_target(ele: &ElementRef<'_>) -> bool {
+    (is_natively_focusable(ele) || has_interactive_role(ele))
+        && !is_disabled(ele)
+        && !is_hidden_subtree(ele)
+}
+
+/// the pointer targets with a layout
+fn targets<'a>(nodes: &ElementNodes<'a>, auditor: &Auditor<'_>) -> Vec<Target<'a>> {
+    let mut styles = ComputedStyles::new(auditor);
+    let mut targets = Vec::new();
+
+    for node in nodes {
+        for ele in descendant_elements(&node.0) {
+            if is_target(&ele) {
+                if let Some(layout) = auditor.bounds.get(&ele.id()) {
+                    // elements without a computed size are not rendered by the layout
+                    if layout.size.width > 0.0
+                        && layout.size.height > 0.0
+                        && styles.visibility(&ele).is_rendered()
+                    {
+                        targets.push(Target {
+                            element: ele,
+                            layout: *layout,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    targets
+}
+
+/// an undersized target has enough spacing when a circle of the size centered on it does not intersect other targets
+fn has_spacing(target: &Target<'_>, targets: &[Target<'_>], size: f32) -> bool {
+    let center = target.center();
+    let radius = size / 2.0;
+
+    targets
+        .iter()
+        .filter(|other| other.element.id() != target.element.id())
+        .all(|other| {
+            let (x, y) = other.center();
+            let apart = ((center.0 - x).powi(2) + (center.1 - y).powi(2)).sqrt();
+
+            other.distance(center) >= radius && (!other.undersized(size) || apart >= size)
+        })
+}
+
+/// push a validation for the target with the section of the success criterion
+fn push_target(
+    validations: &mut Vec<Validation>,
+    target: &Target<'_>,
+    sc: &str,
+    section: &'static str,
+    locale: &str,
+) {
+    let message = t!(
+        &get_message_i18n_str_raw(&Guideline::InputModalities, "C42", sc, section),
+        locale = locale,
+        width = target.layout.size.width.to_string(),
+        height = target.layout.size.height.to_string()
+    );
+    validations.push(Validation::new(
+        false,
+        section,
+        vec![get_unique_selector(&target.element)],
+        message,
+    ));
+}
+
+/// validate pointer targets are at least 24 by 24 pixels or have enough spacing
+pub fn validate_target_size_minimum(
+    nodes: &ElementNodes,
+    auditor: &Auditor<'_>,
+) -> Vec<Validation> {
+    let targets = targets(nodes, auditor);
+    let mut validations = Vec::new();
+
+    for target in &targets {
+        if target.undersized(TARGET_SIZE_MINIMUM)
+            && !is_inline_text_target(&target.element)
+            && !has_spacing(target, &targets, TARGET_SIZE_MINIMUM)
+        {
+            push_target(&mut validations, target, "8", "", auditor.locale);
+        }
+    }
+
+    validations
+}
+
+/// validate pointer targets are at least 44 by 44 pixels
+pub fn validate_target_size_enhanced(
+    nodes: &ElementNodes,
+    auditor: &Auditor<'_>,
+) -> Vec<Validation> {
+    let mut validations = Vec::new();
+
+    for target in targets(nodes, auditor) {
+        if target.undersized(TARGET_SIZE_ENHANCED) && !is_inline_text_target(&target.element) {
+            push_target(&mut validations, &target, "5", "Enhanced", auditor.locale);
+        }
+    }
+
+    validations
+}
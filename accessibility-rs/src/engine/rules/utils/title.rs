@@ -0,0 +1,153 @@
+use crate::engine::issue::Issue;
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::links::{content_locale, normalize_link_text};
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::wcag_base::{Guideline, Principle};
+use crate::i18n::locales::{get_message_i18n_str_raw, get_phrases_i18n};
+use crate::Auditor;
+use accessibility_scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
+
+/// the characters of a title past which it no longer reads as a short description of the page
+pub const MAX_TITLE_LENGTH: usize = 150;
+/// the amount of distinct pages sharing a title that is reported while crawling a website
+pub const DUPLICATE_TITLE_PAGES: usize = 3;
+/// the file extensions of documents that authoring tools use as the default title
+const FILE_EXTENSIONS: [&str; 13] = [
+    ".html", ".htm", ".xhtml", ".shtml", ".php", ".asp", ".aspx", ".jsp", ".cfm", ".pdf", ".doc",
+    ".docx", ".txt",
+];
+
+/// the title element is the document title in the head and not an svg title
+fn is_document_title(ele: &ElementRef<'_>) -> bool {
+    ele.parent()
+        .and_then(ElementRef::wrap)
+        .is_some_and(|parent| parent.value().name() == "head")
+}
+
+/// the text of the title with the white space collapsed
+pub fn title_text(ele: &ElementRef<'_>) -> String {
+    ele.text()
+        .flat_map(|t| t.split_whitespace())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// the title of the document
+pub fn document_title(document: &Html) -> Option<String> {
+    let selector = unsafe { Selector::parse("head > title").unwrap_unchecked() };
+
+    document
+        .select(&selector)
+        .next()
+        .map(|ele| title_text(&ele))
+}
+
+/// the title is a file name such as index.html
+pub fn is_file_name(title: &str) -> bool {
+    let title = title.trim().to_ascii_lowercase();
+
+    !title.contains(char::is_whitespace)
+        && FILE_EXTENSIONS
+            .iter()
+            .any(|ext| title.len() > ext.len() && title.ends_with(ext))
+}
+
+/// the title is the default text of a template or authoring tool
+pub fn is_placeholder_title(title: &str, phrases: &[String]) -> bool {
+    let title = normalize_link_text(title);
+
+    !title.is_empty() && phrases.contains(&title)
+}
+
+/// the document titles of the nodes matching the predicate
+fn validate_titles(
+    nodes: &ElementNodes,
+    id: &'static str,
+    invalid: impl Fn(&str) -> bool,
+) -> Validation {
+    let elements = nodes
+        .iter()
+        .filter(|node| is_document_title(&node.0) && invalid(&title_text(&node.0)))
+        .map(|node| get_unique_selector(&node.0))
+        .collect::<Vec<_>>();
+
+    Validation::new(elements.is_empty(), id, elements, Default::default())
+}
+
+/// validate the document title has text other than white space
+pub fn validate_empty_title(nodes: &ElementNodes) -> Validation {
+    validate_titles(nodes, "1.EmptyTitle", str::is_empty)
+}
+
+/// validate the document title is not the placeholder of a template or authoring tool
+pub fn validate_placeholder_title(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Validation {
+    let mut phrases = get_phrases_i18n("placeholder_title", &content_locale(auditor));
+    // authoring tools and templates default to english titles in any language
+    phrases.extend(get_phrases_i18n("placeholder_title", "en"));
+
+    validate_titles(nodes, "Placeholder", |title| {
+        is_placeholder_title(title, &phrases)
+    })
+}
+
+/// validate the document title is not a file name
+pub fn validate_file_name_title(nodes: &ElementNodes) -> Validation {
+    validate_titles(nodes, "FileName", is_file_name)
+}
+
+/// validate the document title is short enough to identify the page
+pub fn validate_title_length(nodes: &ElementNodes) -> Validation {
+    validate_titles(nodes, "2.TooLong", |title| {
+        title.chars().count() > MAX_TITLE_LENGTH
+    })
+}
+
+/// the issues of the pages sharing a title with many other pages of the website
+pub fn duplicate_title_issues<'a>(
+    titles: impl IntoIterator<Item = (&'a str, &'a str)>,
+    locale: &str,
+) -> Vec<(String, Issue)> {
+    let mut pages: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for (url, title) in titles {
+        if !title.is_empty() {
+            let urls = pages.entry(title).or_default();
+            if !urls.contains(&url) {
+                urls.push(url);
+            }
+        }
+    }
+
+    let mut issues = Vec::new();
+
+    for urls in pages.values() {
+        if urls.len() >= DUPLICATE_TITLE_PAGES {
+            let message = t!(
+                &get_message_i18n_str_raw(&Guideline::Navigable, "F25", "2", "Duplicate"),
+                locale = locale,
+                pages = urls.len().to_string()
+            );
+            for url in urls {
+                issues.push((
+                    url.to_string(),
+                    Issue::new(
+                        message.clone(),
+                        "title",
+                        &[
+                            "WCAGAAA",
+                            Principle::Operable.as_str(),
+                            Guideline::Navigable.as_str(),
+                            "F25",
+                        ]
+                        .join("."),
+                        "warning",
+                        vec!["title".into()],
+                    ),
+                ));
+            }
+        }
+    }
+
+    issues
+}
@@ -0,0 +1,104 @@
+use crate::engine::rules::rule::Validation;
+use crate::engine::rules::utils::nodes::{get_unique_selector, ElementNodes};
+use crate::engine::rules::wcag_base::Guideline;
+use crate::i18n::locales::get_message_i18n_str_raw;
+use crate::Auditor;
+use accessibility_scraper::ElementRef;
+
+/// the properties of the content of a meta viewport <https://drafts.csswg.org/css-viewport/#parsing-algorithm>
+pub fn viewport_properties(content: &str) -> Vec<(String, String)> {
+    content
+        .split([',', ';'])
+        .filter_map(|property| {
+            let (name, value) = property.split_once('=')?;
+            let name = name.trim().to_ascii_lowercase();
+
+            (!name.is_empty()).then(|| (name, value.trim().to_ascii_lowercase()))
+        })
+        .collect()
+}
+
+/// the user-scalable value prevents zooming
+fn is_user_scalable_disabled(value: &str) -> bool {
+    match value {
+        "yes" | "device-width" | "device-height" => false,
+        "no" => true,
+        // unknown values and numbers between -1 and 1 translate to no
+        value => value.parse::<f32>().map_or(true, |n| n.abs() < 1.0),
+    }
+}
+
+/// the zoom factor of the maximum-scale value
+fn maximum_scale(value: &str) -> f32 {
+    match value {
+        "yes" => 1.0,
+        "device-width" | "device-height" => 10.0,
+        // no and unknown values translate to the smallest zoom factor
+        value => value.parse::<f32>().unwrap_or(0.1),
+    }
+}
+
+/// push a validation for the viewport meta element
+fn push_viewport(
+    validations: &mut Vec<Validation>,
+    ele: &ElementRef<'_>,
+    id: &'static str,
+    message: String,
+) {
+    validations.push(Validation::new(
+        false,
+        id,
+        vec![get_unique_selector(ele)],
+        message,
+    ));
+}
+
+/// validate the meta viewport does not prevent users from zooming the page
+pub fn validate_viewport_zoom(nodes: &ElementNodes, auditor: &Auditor<'_>) -> Vec<Validation> {
+    let required = auditor.options.viewport_maximum_scale;
+    let mut validations = Vec::new();
+
+    for node in nodes {
+        let ele = node.0;
+
+        if ele
+            .attr("name")
+            .is_some_and(|name| name.trim().eq_ignore_ascii_case("viewport"))
+        {
+            for (name, value) in viewport_properties(ele.attr("content").unwrap_or_default()) {
+                match name.as_str() {
+                    "user-scalable" if is_user_scalable_disabled(&value) => {
+                        let message = t!(
+                            &get_message_i18n_str_raw(
+                                &Guideline::Distinguishable,
+                                "G142",
+                                "4",
+                                "UserScalable"
+                            ),
+                            locale = auditor.locale,
+                            value = value
+                        );
+                        push_viewport(&mut validations, &ele, "UserScalable", message);
+                    }
+                    "maximum-scale" if maximum_scale(&value) < required => {
+                        let message = t!(
+                            &get_message_i18n_str_raw(
+                                &Guideline::Distinguishable,
+                                "G142",
+                                "4",
+                                "MaximumScale"
+                            ),
+                            locale = auditor.locale,
+                            value = value,
+                            required = required.to_string()
+                        );
+                        push_viewport(&mut validations, &ele, "MaximumScale", message);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    validations
+}
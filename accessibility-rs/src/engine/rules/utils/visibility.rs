@@ -0,0 +1,109 @@
+use crate::engine::rules::utils::names::is_hidden_from_at;
+use accessibility_scraper::ElementRef;
+use accessibility_tree::style::values::{
+    Clip, ClipPath, Display, LengthOrPercentageOrAuto, Position,
+};
+use accessibility_tree::style::ComputedValues;
+
+/// the offset used to move content out of the viewport
+const OFF_SCREEN: f32 = -999.0;
+
+/// how an element is presented on screen and to assistive technology
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Visibility {
+    /// painted and exposed to assistive technology
+    Visible,
+    /// not painted but exposed to assistive technology like the visually hidden pattern
+    VisuallyHidden,
+    /// painted but hidden from assistive technology with aria-hidden
+    AriaHidden,
+    /// neither painted nor exposed to assistive technology
+    Hidden,
+}
+
+impl Visibility {
+    /// the element is painted on screen
+    pub fn is_rendered(self) -> bool {
+        matches!(self, Visibility::Visible | Visibility::AriaHidden)
+    }
+
+    /// the element is exposed to assistive technology
+    pub fn is_exposed(self) -> bool {
+        matches!(self, Visibility::Visible | Visibility::VisuallyHidden)
+    }
+
+    /// the visibility once the element is not painted
+    fn hide_visually(self) -> Self {
+        match self {
+            Visibility::Visible => Visibility::VisuallyHidden,
+            Visibility::AriaHidden => Visibility::Hidden,
+            visibility => visibility,
+        }
+    }
+
+    /// the visibility once the element is hidden from assistive technology
+    fn hide_from_at(self) -> Self {
+        match self {
+            Visibility::Visible => Visibility::AriaHidden,
+            Visibility::VisuallyHidden => Visibility::Hidden,
+            visibility => visibility,
+        }
+    }
+}
+
+/// the length is a size of at most one pixel
+fn is_collapsed_length(length: LengthOrPercentageOrAuto) -> bool {
+    matches!(length, LengthOrPercentageOrAuto::Length(l) if l.px <= 1.0)
+}
+
+/// the style clips the element out of view with the visually hidden pattern or moves it off screen
+pub fn is_visually_hidden_style(style: &ComputedValues, layout: Option<&taffy::Layout>) -> bool {
+    let box_ = &style.box_;
+    let effects = &style.effects;
+    let collapsed = match layout {
+        Some(layout) => layout.size.width <= 1.0 && layout.size.height <= 1.0,
+        _ => is_collapsed_length(box_.width) && is_collapsed_length(box_.height),
+    };
+    let off_screen = box_.position == Position::Absolute
+        && [box_.left, box_.top].into_iter().any(
+            |offset| matches!(offset, LengthOrPercentageOrAuto::Length(l) if l.px <= OFF_SCREEN),
+        );
+
+    effects.opacity.is_transparent()
+        || effects.clip == Clip::Hidden && box_.position == Position::Absolute
+        || effects.clip_path == ClipPath::Hidden
+        || box_.overflow.clips() && collapsed
+        || off_screen
+}
+
+/// the visibility the element passes to its descendants from the visibility of the parent
+pub fn subtree_visibility(
+    parent: Visibility,
+    ele: &ElementRef<'_>,
+    style: &ComputedValues,
+    layout: Option<&taffy::Layout>,
+) -> Visibility {
+    if style.box_.display == Display::None || ele.attr("hidden").is_some() {
+        return Visibility::Hidden;
+    }
+
+    let mut visibility = parent;
+
+    if is_hidden_from_at(ele) {
+        visibility = visibility.hide_from_at();
+    }
+    if is_visually_hidden_style(style, layout) {
+        visibility = visibility.hide_visually();
+    }
+
+    visibility
+}
+
+/// the visibility of the element itself where visibility: hidden can be reverted by the descendants
+pub fn element_visibility(subtree: Visibility, style: &ComputedValues) -> Visibility {
+    if style.inherited_box.visibility.is_hidden() {
+        Visibility::Hidden
+    } else {
+        subtree
+    }
+}
@@ -59,16 +59,22 @@ impl Principle {
 pub enum Guideline {
     /// Provide text alternatives for any non-text content so that it can be changed into other forms people need.
     TextAlternatives,
+    /// Provide alternatives for time-based media.
+    TimeBasedMedia,
     /// Create content that can be presented in different ways (for example simpler layout) without losing information or structure.
     Adaptable,
     /// Make it easier for users to see and hear content including separating foreground from background.
     Distinguishable,
+    /// Make all functionality available from a keyboard.
+    KeyboardAccessible,
     /// Provide users enough time to read and use content.
     EnoughTime,
     /// Do not design content in a way that is known to cause seizures.
     Seizures,
     /// Provide ways to help users navigate, find content, and determine where they are.
     Navigable,
+    /// Make it easier for users to operate functionality through various inputs beyond keyboard.
+    InputModalities,
     /// Make text content readable and understandable.
     Readable,
     /// Make Web pages appear and operate in predictable ways.
@@ -82,11 +88,14 @@ impl Guideline {
     pub fn as_str(&self) -> &'static str {
         match self {
             Guideline::TextAlternatives => "Guideline1_1",
+            Guideline::TimeBasedMedia => "Guideline1_2",
             Guideline::Adaptable => "Guideline1_3",
             Guideline::Distinguishable => "Guideline1_4",
+            Guideline::KeyboardAccessible => "Guideline2_1",
             Guideline::EnoughTime => "Guideline2_2",
             Guideline::Seizures => "Guideline2_3",
             Guideline::Navigable => "Guideline2_4",
+            Guideline::InputModalities => "Guideline2_5",
             Guideline::Readable => "Guideline3_1",
             Guideline::Predictable => "Guideline3_2",
             Guideline::Compatible => "Guideline4_1",
@@ -1,9 +1,56 @@
 use crate::engine::rules::rule::{Rule, Validation};
 use crate::engine::rules::techniques::Techniques;
+use crate::engine::rules::utils::abbreviations::validate_abbreviations;
+use crate::engine::rules::utils::alt_text::{validate_adjacent_link_alt, validate_alt_text};
+use crate::engine::rules::utils::autocomplete::{
+    validate_autocomplete_control, validate_autocomplete_faulty, validate_autocomplete_off,
+};
+use crate::engine::rules::utils::buttons::{validate_button_name, validate_submit_button};
+use crate::engine::rules::utils::contrast::{
+    validate_component_contrast, validate_focus_indicator_contrast, validate_text_contrast,
+    validate_text_contrast_image, CONTRAST_ENHANCED, CONTRAST_MINIMUM,
+};
+use crate::engine::rules::utils::focus::validate_focus_visible;
+use crate::engine::rules::utils::grouping::{
+    validate_fieldset_radio_names, validate_single_radio, validate_ungrouped_controls,
+};
+use crate::engine::rules::utils::keyboard::{
+    validate_mouse_only_handlers, validate_nested_interactive, validate_non_interactive_tabindex,
+    validate_not_focusable, validate_positive_tabindex,
+};
+use crate::engine::rules::utils::language::{
+    is_language_consistent, is_valid_language_attr, validate_language_of_parts,
+};
+use crate::engine::rules::utils::links::{
+    validate_link_color_only, validate_link_focus_cue, validate_link_text_context,
+    validate_link_text_destinations, validate_link_text_only,
+};
+use crate::engine::rules::utils::media::{
+    validate_embedded_media, validate_media_autoplay, validate_media_autoplay_controls,
+    validate_media_captions, validate_media_descriptions, validate_media_transcript,
+    validate_track_attributes,
+};
+use crate::engine::rules::utils::motion::{validate_infinite_animations, validate_reduced_motion};
 use crate::engine::rules::utils::nodes::{
     get_unique_selector, has_alt, has_alt_prop, has_prop, has_prop_value, validate_empty_nodes,
     validate_missing_attr,
 };
+use crate::engine::rules::utils::presentation::{
+    validate_layout_tables, validate_presentational_attributes, validate_presentational_element,
+};
+use crate::engine::rules::utils::readability::validate_readability;
+use crate::engine::rules::utils::sensory::validate_sensory_characteristics;
+use crate::engine::rules::utils::svg::{
+    validate_svg_control_name, validate_svg_decorative, validate_svg_name,
+};
+use crate::engine::rules::utils::target::{
+    validate_target_size_enhanced, validate_target_size_minimum,
+};
+use crate::engine::rules::utils::title::{
+    validate_empty_title, validate_file_name_title, validate_placeholder_title,
+    validate_title_length,
+};
+use crate::engine::rules::utils::viewport::validate_viewport_zoom;
 use crate::engine::rules::wcag_base::{Guideline, IssueType, Principle};
 use crate::i18n::locales::get_message_i18n_str_raw;
 use accessibility_scraper::{ElementRef, Selector};
@@ -22,28 +69,17 @@ lazy_static! {
                     Validation::new_issue(!n.attr("lang").unwrap_or_default().is_empty() || !n.attr("xml:lang").unwrap_or_default().is_empty(), "2").into()
                 }),
                 Rule::new(Techniques::H57.into(), IssueType::Error, Principle::Understandable, Guideline::Readable, "1", |nodes, _auditor| {
-                    let lang = nodes[0].0.attr("lang").unwrap_or_default();
-                    let alphabetic = lang.chars().all(|x| x.is_alphabetic());
                     // <https://www.rfc-editor.org/rfc/bcp/bcp47.txt>
-                    Validation::new_issue(if lang.len() > 3 {
-                        let mut c = lang.chars();
-                        let has_underscore = c.nth(2).unwrap_or_default() == '_' || lang.len() >= 4 && c.nth(1).unwrap_or_default() == '_';
-                        alphabetic && has_underscore && lang.len() < 12
-                    } else {
-                        alphabetic && lang.len() < 12
-                    }, "3.Lang").into()
+                    Validation::new_issue(is_valid_language_attr(&nodes[0].0, "lang"), "3.Lang").into()
+                }),
+                Rule::new(Techniques::H57.into(), IssueType::Error, Principle::Understandable, Guideline::Readable, "1", |nodes, _auditor| {
+                    Validation::new_issue(is_valid_language_attr(&nodes[0].0, "xml:lang"), "3.XmlLang").into()
                 }),
                 Rule::new(Techniques::H57.into(), IssueType::Error, Principle::Understandable, Guideline::Readable, "1", |nodes, _auditor| {
-                    let lang = nodes[0].0.attr("xml:lang").unwrap_or_default();
-                    let alphabetic = lang.chars().all(|x| x == '_' || x.is_alphabetic());
-                   // <https://www.rfc-editor.org/rfc/bcp/bcp47.txt>
-                   Validation::new_issue(if lang.len() > 3 {
-                        let mut c = lang.chars();
-                        let has_underscore = c.nth(2).unwrap_or_default() == '_' || lang.len() >= 4 && c.nth(1).unwrap_or_default() == '_';
-                        alphabetic && has_underscore && lang.len() < 12
-                    } else {
-                        alphabetic && lang.len() < 12
-                    }, "3.XmlLang").into()
+                    Validation::new_issue(is_language_consistent(&nodes[0].0), "3.Mismatch").into()
+                }),
+                Rule::new(Techniques::H58.into(), IssueType::Error, Principle::Understandable, Guideline::Readable, "2", |nodes, _auditor| {
+                    validate_language_of_parts(nodes).into()
                 }),
                 Rule::new(Techniques::H25.into(), IssueType::Error, Principle::Operable, Guideline::Navigable, "2", |nodes, _auditor| {
                     let selector = unsafe { Selector::parse("head > title").unwrap_unchecked() };
@@ -84,110 +120,84 @@ lazy_static! {
 
                     Validation::new_issue(valid, "2").into()
                 }),
+                Rule::new(Techniques::G142.into(), IssueType::Error, Principle::Perceivable, Guideline::Distinguishable, "4", |nodes, auditor| {
+                    validate_viewport_zoom(nodes, auditor).into()
+                }),
             ])),
             ("title", Vec::from([
                 Rule::new(Techniques::H25.into(), IssueType::Error, Principle::Operable, Guideline::Navigable, "2", |nodes, _auditor| {
-                    let mut valid = true;
-                    for node in nodes {
-                        let e = node.0.inner_html().is_empty();
-                        if e {
-                            valid = false;
-                        }
-                    }
-                    Validation::new_issue(!nodes.is_empty() || valid, "1.EmptyTitle").into()
+                    validate_empty_title(nodes).into()
+                }),
+                Rule::new(Techniques::F25.into(), IssueType::Warning, Principle::Operable, Guideline::Navigable, "2", |nodes, auditor| {
+                    validate_placeholder_title(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::F25.into(), IssueType::Error, Principle::Operable, Guideline::Navigable, "2", |nodes, _auditor| {
+                    validate_file_name_title(nodes).into()
+                }),
+                Rule::new(Techniques::H25.into(), IssueType::Warning, Principle::Operable, Guideline::Navigable, "2", |nodes, _auditor| {
+                    validate_title_length(nodes).into()
                 }),
             ])),
             ("body", Vec::from([
-                Rule::new(Techniques::G18.into(), IssueType::Error, Principle::Perceivable, Guideline::Distinguishable, "1", |nodes, auditor| {
-                    use rgb::RGB8;
-                    let mut validation_errors = Vec::new();
-
-                    // todo: test for multiple contrast rules at once.
-                    for node in nodes {
-                        if node.0.has_children() {
-                            let mut children = node.0.children();
-
-                            while let Some(el) = children.next() {
-                                match ElementRef::wrap(el) {
-                                    Some(element) => {
-                                        if vec![
-                                            "h1",
-                                            "h2",
-                                            "h3",
-                                            "h4",
-                                            "h5",
-                                            "h6",
-                                            "a",
-                                            "button",
-                                            "p",
-                                            "img",
-                                            "span",
-                                            "div",
-                                            "li",
-                                            "ol",
-                                            "td",
-                                            "th",
-                                            "tr",
-                                            "textarea",
-                                            "select",
-                                            "input"].contains(&element.value().name()) {
-                                            let style = accessibility_tree::style::cascade::style_for_element_ref(
-                                                &element,
-                                                &auditor.author,
-                                                &auditor.document
-                                            );
-
-                                            let font_size = style.font.font_size.0;
-                                            let text_color = style.color.color;
-
-                                            match element.parent() {
-                                                Some(parent_node) => {
-                                                    match ElementRef::wrap(parent_node) {
-                                                        Some(parent_element) => {
-                                                            let parent_style = accessibility_tree::style::cascade::style_for_element_ref(
-                                                                &parent_element,
-                                                                &auditor.author,
-                                                                &auditor.document,
-                                                            );
-
-                                                            match parent_style.background.background_color {
-                                                                cssparser::Color::RGBA(c) => {
-                                                                    let parent_element_background_color = RGB8::from([c.red, c.green, c.blue]);
-                                                                    let current_element_text_color = RGB8::from([text_color.red, text_color.green, text_color.blue]);
-                                                                    let contrast_ratio = contrast::contrast::<_, f32>(parent_element_background_color, current_element_text_color);
-                                                                    let min_contrast = if font_size.px <= 16.00 { 4.00 } else { 3.00 };
-
-                                                                    if contrast_ratio <= min_contrast {
-                                                                        let message =  t!(
-                                                                            &get_message_i18n_str_raw(
-                                                                                &Guideline::Distinguishable,
-                                                                                "",
-                                                                                "3_G18_or_G145.Fail",
-                                                                                ""),
-                                                                            locale = auditor.locale,
-                                                                            required = min_contrast.to_string(),
-                                                                            value = contrast_ratio.to_string());
-
-                                                                        validation_errors.push(Validation::new_custom_issue(false, "", message).into())
-                                                                    }
-                                                                }
-                                                                _ => ()
-                                                            }
-                                                        }
-                                                        _ => ()
-                                                    }
-                                                }
-                                                _ => ()
-                                            }
-                                        }
-                                    }
-                                    _ => ()
-                                }
-                            }
-                        }
-                    }
-
-                    crate::engine::rules::rule::RuleValidation::Multi(validation_errors)
+                Rule::new(Techniques::G18.into(), IssueType::Error, Principle::Perceivable, Guideline::Distinguishable, "3", |_nodes, auditor| {
+                    validate_text_contrast(auditor, &CONTRAST_MINIMUM).into()
+                }),
+                Rule::new(Techniques::G17.into(), IssueType::Error, Principle::Perceivable, Guideline::Distinguishable, "6", |_nodes, auditor| {
+                    validate_text_contrast(auditor, &CONTRAST_ENHANCED).into()
+                }),
+                Rule::new(Techniques::G18.into(), IssueType::Notice, Principle::Perceivable, Guideline::Distinguishable, "3", |_nodes, auditor| {
+                    validate_text_contrast_image(auditor, &CONTRAST_MINIMUM).into()
+                }),
+                Rule::new(Techniques::G17.into(), IssueType::Notice, Principle::Perceivable, Guideline::Distinguishable, "6", |_nodes, auditor| {
+                    validate_text_contrast_image(auditor, &CONTRAST_ENHANCED).into()
+                }),
+                Rule::new(Techniques::G209.into(), IssueType::Error, Principle::Perceivable, Guideline::Distinguishable, "11", |nodes, auditor| {
+                    validate_component_contrast(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::G195.into(), IssueType::Error, Principle::Perceivable, Guideline::Distinguishable, "11", |nodes, auditor| {
+                    validate_focus_indicator_contrast(nodes, auditor).into()
+                }),
+                Rule::new(vec![Techniques::F54, Techniques::F42].into(), IssueType::Error, Principle::Operable, Guideline::KeyboardAccessible, "1", |nodes, _auditor| {
+                    validate_not_focusable(nodes).into()
+                }),
+                Rule::new(Techniques::SCR20.into(), IssueType::Warning, Principle::Operable, Guideline::KeyboardAccessible, "1", |nodes, _auditor| {
+                    validate_mouse_only_handlers(nodes).into()
+                }),
+                Rule::new(Techniques::F44.into(), IssueType::Warning, Principle::Operable, Guideline::Navigable, "3", |nodes, _auditor| {
+                    validate_positive_tabindex(nodes).into()
+                }),
+                Rule::new(Techniques::H4.into(), IssueType::Warning, Principle::Operable, Guideline::Navigable, "3", |nodes, _auditor| {
+                    validate_non_interactive_tabindex(nodes).into()
+                }),
+                Rule::new(Techniques::H91.into(), IssueType::Error, Principle::Robust, Guideline::Compatible, "2", |nodes, auditor| {
+                    validate_nested_interactive(nodes, auditor.locale).into()
+                }),
+                Rule::new(Techniques::F78.into(), IssueType::Warning, Principle::Operable, Guideline::Navigable, "7", |nodes, auditor| {
+                    validate_focus_visible(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::C42.into(), IssueType::Warning, Principle::Operable, Guideline::InputModalities, "8", |nodes, auditor| {
+                    validate_target_size_minimum(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::C42.into(), IssueType::Warning, Principle::Operable, Guideline::InputModalities, "5", |nodes, auditor| {
+                    validate_target_size_enhanced(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::F16.into(), IssueType::Warning, Principle::Operable, Guideline::EnoughTime, "2", |nodes, auditor| {
+                    validate_infinite_animations(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::C39.into(), IssueType::Warning, Principle::Operable, Guideline::Seizures, "3", |nodes, auditor| {
+                    validate_reduced_motion(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::G96.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "3", |nodes, auditor| {
+                    validate_sensory_characteristics(nodes, auditor).into()
+                }),
+                Rule::new(vec![Techniques::G102, Techniques::H28].into(), IssueType::Notice, Principle::Understandable, Guideline::Readable, "4", |nodes, auditor| {
+                    validate_abbreviations(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::G153.into(), IssueType::Notice, Principle::Understandable, Guideline::Readable, "5", |nodes, auditor| {
+                    validate_readability(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::H49.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
+                    validate_presentational_attributes(nodes).into()
                 }),
             ])),
             ("iframe", Vec::from([
@@ -201,25 +211,8 @@ lazy_static! {
                 }),
             ])),
             ("form", Vec::from([
-                Rule::new(Techniques::H32.into(), IssueType::Error, Principle::Operable, Guideline::Predictable, "2", |nodes, _auditor| {
-                    let mut valid = false;
-                    let mut elements = Vec::new();
-                    let selector = unsafe { Selector::parse("button[type=submit]").unwrap_unchecked() };
-
-                    for ele in nodes {
-                        let ele = ele.0;
-                        let e = ele.select(&selector);
-                        let c = e.count();
-
-                       if c == 1 {
-                            valid = true;
-                       } else {
-                            valid = false;
-                            elements.push(get_unique_selector(&ele))
-                        }
-                    }
-
-                    Validation::new(valid, "2", elements, Default::default()).into()
+                Rule::new(Techniques::H32.into(), IssueType::Error, Principle::Understandable, Guideline::Predictable, "2", |nodes, _auditor| {
+                    validate_submit_button(nodes).into()
                 }),
                 Rule::new(Techniques::H36.into(), IssueType::Error, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, _auditor| {
                     let mut valid = false;
@@ -270,6 +263,9 @@ lazy_static! {
 
                     Validation::new(valid, "EG5", elements, Default::default()).into()
                 }),
+                Rule::new(Techniques::H2.into(), IssueType::Error, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, _auditor| {
+                    validate_adjacent_link_alt(nodes).into()
+                }),
                 Rule::new(Techniques::H30.into(), IssueType::Error, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, _auditor| {
                     let mut valid = true;
                     let selector = unsafe { Selector::parse("img").unwrap_unchecked() };
@@ -322,6 +318,21 @@ lazy_static! {
                     }
                     Validation::new(valid, "A.EmptyNoId", elements, Default::default()).into()
                 }),
+                Rule::new(vec![Techniques::H77, Techniques::H78, Techniques::H79, Techniques::H80, Techniques::H81].into(), IssueType::Warning, Principle::Operable, Guideline::Navigable, "4", |nodes, auditor| {
+                    validate_link_text_context(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::H30.into(), IssueType::Warning, Principle::Operable, Guideline::Navigable, "9", |nodes, auditor| {
+                    validate_link_text_only(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::H30.into(), IssueType::Warning, Principle::Operable, Guideline::Navigable, "9", |nodes, auditor| {
+                    validate_link_text_destinations(nodes, auditor.locale).into()
+                }),
+                Rule::new(Techniques::F73.into(), IssueType::Error, Principle::Perceivable, Guideline::Distinguishable, "1", |nodes, auditor| {
+                    validate_link_color_only(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::G183.into(), IssueType::Warning, Principle::Perceivable, Guideline::Distinguishable, "1", |nodes, auditor| {
+                    validate_link_focus_cue(nodes, auditor).into()
+                }),
             ])),
             ("img", Vec::from([
                 Rule::new(Techniques::H37.into(), IssueType::Error, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, _auditor| {
@@ -330,11 +341,10 @@ lazy_static! {
 
                     for ele in nodes {
                         let ele = ele.0;
-                        let alt = has_alt(ele);
-                        if !alt {
+                        if !has_alt(ele) {
+                            valid = false;
                             elements.push(get_unique_selector(&ele))
                         }
-                        valid = alt;
                     }
 
                     Validation::new(valid, "", elements, Default::default()).into()
@@ -353,6 +363,20 @@ lazy_static! {
 
                     Validation::new(valid, "1", elements, Default::default()).into()
                 }),
+                Rule::new(Techniques::G94.into(), IssueType::Warning, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, auditor| {
+                    validate_alt_text(nodes, auditor).into()
+                }),
+            ])),
+            ("svg", Vec::from([
+                Rule::new(vec![Techniques::ARIA6, Techniques::ARIA10].into(), IssueType::Error, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, _auditor| {
+                    validate_svg_name(nodes).into()
+                }),
+                Rule::new(vec![Techniques::ARIA6, Techniques::ARIA10].into(), IssueType::Error, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, _auditor| {
+                    validate_svg_control_name(nodes).into()
+                }),
+                Rule::new(vec![Techniques::ARIA6, Techniques::ARIA10].into(), IssueType::Warning, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, _auditor| {
+                    validate_svg_decorative(nodes).into()
+                }),
             ])),
             ("h1", Vec::from([
                 Rule::new(Techniques::H42.into(), IssueType::Error, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
@@ -537,12 +561,97 @@ lazy_static! {
 
                     Validation::new(valid, "", elements, message).into()
                 }),
+                Rule::new(Techniques::H98.into(), IssueType::Error, Principle::Perceivable, Guideline::Adaptable, "5", |nodes, auditor| {
+                    validate_autocomplete_faulty(nodes, auditor.locale).into()
+                }),
+                Rule::new(Techniques::H98.into(), IssueType::Error, Principle::Perceivable, Guideline::Adaptable, "5", |nodes, auditor| {
+                    validate_autocomplete_control(nodes, auditor.locale).into()
+                }),
+                Rule::new(Techniques::H98.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "5", |nodes, _auditor| {
+                    validate_autocomplete_off(nodes).into()
+                }),
+                Rule::new(Techniques::H71.into(), IssueType::Error, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
+                    validate_ungrouped_controls(nodes, "radio", 2).into()
+                }),
+                Rule::new(Techniques::H71.into(), IssueType::Warning, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
+                    validate_ungrouped_controls(nodes, "checkbox", 2).into()
+                }),
+                Rule::new(Techniques::H71.into(), IssueType::Warning, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, auditor| {
+                    validate_single_radio(nodes, auditor.locale).into()
+                }),
+            ])),
+            ("button", Vec::from([
+                Rule::new(Techniques::H91.into(), IssueType::Error, Principle::Robust, Guideline::Compatible, "2", |nodes, _auditor| {
+                    validate_button_name(nodes).into()
+                }),
+            ])),
+            ("select", Vec::from([
+                Rule::new(Techniques::H98.into(), IssueType::Error, Principle::Perceivable, Guideline::Adaptable, "5", |nodes, auditor| {
+                    validate_autocomplete_faulty(nodes, auditor.locale).into()
+                }),
+                Rule::new(Techniques::H98.into(), IssueType::Error, Principle::Perceivable, Guideline::Adaptable, "5", |nodes, auditor| {
+                    validate_autocomplete_control(nodes, auditor.locale).into()
+                }),
+                Rule::new(Techniques::H98.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "5", |nodes, _auditor| {
+                    validate_autocomplete_off(nodes).into()
+                }),
+            ])),
+            ("textarea", Vec::from([
+                Rule::new(Techniques::H98.into(), IssueType::Error, Principle::Perceivable, Guideline::Adaptable, "5", |nodes, auditor| {
+                    validate_autocomplete_faulty(nodes, auditor.locale).into()
+                }),
+                Rule::new(Techniques::H98.into(), IssueType::Error, Principle::Perceivable, Guideline::Adaptable, "5", |nodes, auditor| {
+                    validate_autocomplete_control(nodes, auditor.locale).into()
+                }),
+                Rule::new(Techniques::H98.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "5", |nodes, _auditor| {
+                    validate_autocomplete_off(nodes).into()
+                }),
             ])),
             ("blink", Vec::from([
                 Rule::new(Techniques::F47.into(), IssueType::Error, Principle::Operable, Guideline::EnoughTime, "2", |nodes, _auditor| {
                     Validation::new_issue(nodes.is_empty(), "").into()
                 }),
             ])),
+            ("marquee", Vec::from([
+                Rule::new(Techniques::F16.into(), IssueType::Error, Principle::Operable, Guideline::EnoughTime, "2", |nodes, _auditor| {
+                    Validation::new(nodes.is_empty(), "Marquee", nodes.iter().map(|node| get_unique_selector(&node.0)).collect(), Default::default()).into()
+                }),
+            ])),
+            ("font", Vec::from([
+                Rule::new(Techniques::H49.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
+                    validate_presentational_element(nodes, "Font").into()
+                }),
+            ])),
+            ("basefont", Vec::from([
+                Rule::new(Techniques::H49.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
+                    validate_presentational_element(nodes, "Basefont").into()
+                }),
+            ])),
+            ("center", Vec::from([
+                Rule::new(Techniques::H49.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
+                    validate_presentational_element(nodes, "Center").into()
+                }),
+            ])),
+            ("big", Vec::from([
+                Rule::new(Techniques::H49.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
+                    validate_presentational_element(nodes, "Big").into()
+                }),
+            ])),
+            ("tt", Vec::from([
+                Rule::new(Techniques::H49.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
+                    validate_presentational_element(nodes, "Tt").into()
+                }),
+            ])),
+            ("strike", Vec::from([
+                Rule::new(Techniques::H49.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
+                    validate_presentational_element(nodes, "Strike").into()
+                }),
+            ])),
+            ("table", Vec::from([
+                Rule::new(Techniques::H49.into(), IssueType::Notice, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, _auditor| {
+                    validate_layout_tables(nodes).into()
+                }),
+            ])),
             ("object", Vec::from([
                 Rule::new(Techniques::F47.into(), IssueType::Error, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, _auditor| {
                     let mut valid = true;
@@ -559,6 +668,50 @@ lazy_static! {
 
                     Validation::new(valid, "", elements, Default::default()).into()
                 }),
+                Rule::new(vec![Techniques::G87, Techniques::G93].into(), IssueType::Notice, Principle::Perceivable, Guideline::TimeBasedMedia, "2", |nodes, _auditor| {
+                    validate_embedded_media(nodes).into()
+                }),
+            ])),
+            ("embed", Vec::from([
+                Rule::new(vec![Techniques::G87, Techniques::G93].into(), IssueType::Notice, Principle::Perceivable, Guideline::TimeBasedMedia, "2", |nodes, _auditor| {
+                    validate_embedded_media(nodes).into()
+                }),
+            ])),
+            ("video", Vec::from([
+                Rule::new(vec![Techniques::G87, Techniques::G93].into(), IssueType::Notice, Principle::Perceivable, Guideline::TimeBasedMedia, "2", |nodes, _auditor| {
+                    validate_media_captions(nodes).into()
+                }),
+                Rule::new(vec![Techniques::G69, Techniques::G78, Techniques::G173, Techniques::G8].into(), IssueType::Notice, Principle::Perceivable, Guideline::TimeBasedMedia, "3", |nodes, auditor| {
+                    validate_media_descriptions(nodes, auditor, true).into()
+                }),
+                Rule::new(vec![Techniques::G78, Techniques::G173, Techniques::G8].into(), IssueType::Notice, Principle::Perceivable, Guideline::TimeBasedMedia, "5", |nodes, auditor| {
+                    validate_media_descriptions(nodes, auditor, false).into()
+                }),
+                Rule::new(Techniques::F93.into(), IssueType::Notice, Principle::Perceivable, Guideline::Distinguishable, "2", |nodes, _auditor| {
+                    validate_media_autoplay(nodes).into()
+                }),
+                Rule::new(Techniques::F23.into(), IssueType::Notice, Principle::Perceivable, Guideline::Distinguishable, "2", |nodes, _auditor| {
+                    validate_media_autoplay_controls(nodes).into()
+                }),
+            ])),
+            ("audio", Vec::from([
+                Rule::new(Techniques::G158.into(), IssueType::Notice, Principle::Perceivable, Guideline::TimeBasedMedia, "1", |nodes, auditor| {
+                    validate_media_transcript(nodes, auditor).into()
+                }),
+                Rule::new(Techniques::F93.into(), IssueType::Notice, Principle::Perceivable, Guideline::Distinguishable, "2", |nodes, _auditor| {
+                    validate_media_autoplay(nodes).into()
+                }),
+                Rule::new(Techniques::F23.into(), IssueType::Notice, Principle::Perceivable, Guideline::Distinguishable, "2", |nodes, _auditor| {
+                    validate_media_autoplay_controls(nodes).into()
+                }),
+            ])),
+            ("track", Vec::from([
+                Rule::new(Techniques::H95.into(), IssueType::Error, Principle::Perceivable, Guideline::TimeBasedMedia, "2", |nodes, _auditor| {
+                    validate_track_attributes(nodes, "srclang", "SrcLang").into()
+                }),
+                Rule::new(Techniques::H95.into(), IssueType::Warning, Principle::Perceivable, Guideline::TimeBasedMedia, "2", |nodes, _auditor| {
+                    validate_track_attributes(nodes, "label", "Label").into()
+                }),
             ])),
             ("area",Vec::from([
                 Rule::new(Techniques::H24.into(), IssueType::Error, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, _auditor| {
@@ -617,6 +770,9 @@ lazy_static! {
 
                     Validation::new(valid, "NoLegend", elements, Default::default()).into()
                 }),
+                Rule::new(Techniques::H71.into(), IssueType::Warning, Principle::Perceivable, Guideline::Adaptable, "1", |nodes, auditor| {
+                    validate_fieldset_radio_names(nodes, auditor.locale).into()
+                }),
             ])),
             ("applet", Vec::from([
                 Rule::new(Techniques::H35.into(), IssueType::Error, Principle::Perceivable, Guideline::TextAlternatives, "1", |nodes, _auditor| {
@@ -4,6 +4,8 @@ use accessibility_tree::style::values::LengthOrPercentageOrAuto;
 use accessibility_tree::style::ComputedValues;
 use accessibility_tree::style::StyleSet;
 use ego_tree::NodeRef;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 use taffy::prelude::*;
@@ -76,13 +78,27 @@ pub fn node_layout_style(style: Arc<ComputedValues>, element: &ElementRef) -> St
     }
 }
 
-/// push leaf
+/// approximate text layout style using the font size of the parent element
+pub fn text_layout_style(text: &str, font_size: f32) -> Style {
+    let chars = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    Style {
+        size: Size {
+            width: length(chars.chars().count() as f32 * font_size * 0.5),
+            height: length(font_size * 1.2),
+        },
+        ..Default::default()
+    }
+}
+
+/// push leaf mapping the element to the layout node
 pub fn push_leaf<'a, 'b, 'c>(
     node: &NodeRef<'_, accessibility_scraper::Node>,
     author: &StyleSet,
     document: &'a Html,
     taffy: &mut TaffyTree,
     l_leafs: &mut Vec<NodeId>,
+    layout_nodes: &mut HashMap<ego_tree::NodeId, NodeId>,
 ) {
     match ElementRef::wrap(*node) {
         Some(element) => {
@@ -92,23 +108,36 @@ pub fn push_leaf<'a, 'b, 'c>(
                     &element, &author, &document,
                 );
 
-                if node.has_children() {
+                let layout_node = if node.has_children() {
                     let children = node.children();
                     let mut child_leafs: Vec<NodeId> = vec![];
+                    let font_size = style.font.font_size.0.px;
 
                     // iterate all children and push into one leaf
                     for child in children {
-                        push_leaf(&child, author, document, taffy, &mut child_leafs);
+                        match child.value().as_text() {
+                            Some(text) if !text.trim().is_empty() => child_leafs
+                                .push(taffy.new_leaf(text_layout_style(text, font_size)).unwrap()),
+                            _ => push_leaf(
+                                &child,
+                                author,
+                                document,
+                                taffy,
+                                &mut child_leafs,
+                                layout_nodes,
+                            ),
+                        }
                     }
 
-                    l_leafs.push(
-                        taffy
-                            .new_with_children(node_layout_style(style, &element), &child_leafs)
-                            .unwrap(),
-                    );
+                    taffy
+                        .new_with_children(node_layout_style(style, &element), &child_leafs)
+                        .unwrap()
                 } else {
-                    l_leafs.push(taffy.new_leaf(node_layout_style(style, &element)).unwrap());
-                }
+                    taffy.new_leaf(node_layout_style(style, &element)).unwrap()
+                };
+
+                layout_nodes.insert(element.id(), layout_node);
+                l_leafs.push(layout_node);
             }
         }
         _ => (),
@@ -123,10 +152,18 @@ pub fn leaf<'a, 'b, 'c>(
     taffy: &mut TaffyTree,
 ) -> NodeId {
     let mut l_leafs: Vec<NodeId> = vec![];
+    let mut layout_nodes = HashMap::new();
     let mut children = element.children();
 
     while let Some(child) = children.next() {
-        push_leaf(&child, author, document, taffy, &mut l_leafs);
+        push_leaf(
+            &child,
+            author,
+            document,
+            taffy,
+            &mut l_leafs,
+            &mut layout_nodes,
+        );
     }
 
     let style =
@@ -142,3 +179,34 @@ pub fn leaf<'a, 'b, 'c>(
     }
     .unwrap()
 }
+
+/// the layout of the node with the location relative to the root of the tree
+pub fn absolute_layout(node: NodeId, taffy: &TaffyTree) -> Option<Layout> {
+    let mut layout = *taffy.layout(node).ok()?;
+    let mut parent = taffy.parent(node);
+
+    while let Some(p) = parent {
+        let location = taffy.layout(p).ok()?.location;
+        layout.location.x += location.x;
+        layout.location.y += location.y;
+        parent = taffy.parent(p);
+    }
+
+    Some(layout)
+}
+
+/// the layouts of the elements in the tree relative to the root
+pub fn layout_bounds(
+    tree: &BTreeMap<&str, Vec<(ElementRef<'_>, Option<NodeId>)>>,
+    taffy: &TaffyTree,
+) -> HashMap<ego_tree::NodeId, Layout> {
+    let mut bounds = HashMap::new();
+
+    for (element, node) in tree.values().flatten() {
+        if let Some(layout) = node.and_then(|node| absolute_layout(node, taffy)) {
+            bounds.insert(element.id(), layout);
+        }
+    }
+
+    bounds
+}
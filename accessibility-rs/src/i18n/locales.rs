@@ -107,3 +107,29 @@ pub fn get_message_i18n(rule: &Rule, section: &str, lang: &str) -> String {
 
     t!(&message, locale = lang)
 }
+
+/// get the locale of the translations for a content language tag
+pub fn get_locale_from_lang(lang: &str) -> String {
+    let lang = lang.trim().replace('_', "-").to_ascii_lowercase();
+    let mut subtags = lang.split('-');
+    let primary = subtags.next().unwrap_or_default();
+
+    if primary == "zh" {
+        if subtags.any(|s| ["tw", "hk", "mo", "hant"].contains(&s)) {
+            "zh_TW".into()
+        } else {
+            "zh_CN".into()
+        }
+    } else {
+        primary.into()
+    }
+}
+
+/// get a list of phrases for the locale stored in the locales phrases
+pub fn get_phrases_i18n(list: &str, lang: &str) -> Vec<String> {
+    t!(&["phrases", list].join("."), locale = lang)
+        .split('|')
+        .map(|phrase| phrase.trim().to_lowercase())
+        .filter(|phrase| !phrase.is_empty())
+        .collect()
+}
@@ -80,6 +80,47 @@ pub enum Conformance {
     WCAGAAA,
 }
 
+/// options to tune the rules of the audit
+#[derive(Debug, Clone)]
+pub struct AuditOptions {
+    /// the lowest maximum-scale allowed for the meta viewport: 2 for 1.4.4 or 5 for stricter audits
+    pub viewport_maximum_scale: f32,
+    /// the highest Flesch-Kincaid grade of the main content before a readability notice, none skips the 3.1.5 check
+    pub readability_grade: Option<f32>,
+    /// the abbreviations and acronyms the team explains elsewhere, such as in a published glossary
+    pub glossary: Vec<String>,
+}
+
+impl Default for AuditOptions {
+    fn default() -> Self {
+        AuditOptions {
+            viewport_maximum_scale: 2.0,
+            readability_grade: None,
+            glossary: Vec::new(),
+        }
+    }
+}
+
+impl AuditOptions {
+    /// add the terms of a glossary file with one term per line, optionally followed by a colon and the expansion
+    pub fn with_glossary_file<P: AsRef<std::path::Path>>(
+        mut self,
+        path: P,
+    ) -> std::io::Result<Self> {
+        let glossary = std::fs::read_to_string(path)?;
+
+        self.glossary.extend(
+            glossary
+                .lines()
+                .map(|line| line.split(':').next().unwrap_or_default().trim())
+                .filter(|term| !term.is_empty() && !term.starts_with('#'))
+                .map(String::from),
+        );
+
+        Ok(self)
+    }
+}
+
 /// configs for the audit
 #[derive(Default)]
 #[cfg(feature = "tokio")]
@@ -94,6 +135,8 @@ pub struct AuditConfig {
     pub locale: String,
     /// the guideline spec
     pub conformance: Conformance,
+    /// options to tune the rules
+    pub options: AuditOptions,
     /// crawl and perform audits on the entire website
     #[cfg(feature = "spider")]
     pub url: String,
@@ -113,6 +156,8 @@ pub struct AuditConfig<'a> {
     pub locale: &'a str,
     /// the guideline spec
     pub conformance: Conformance,
+    /// options to tune the rules
+    pub options: AuditOptions,
     /// crawl and perform audits on the entire website
     #[cfg(feature = "spider")]
     pub url: &'a str,
@@ -206,7 +251,13 @@ impl AuditConfig {
 #[cfg(all(feature = "tokio", not(feature = "spider")))]
 pub async fn audit(config: AuditConfig) -> Vec<Issue> {
     let document = accessibility_scraper::Html::parse_document(&config.html).await;
-    let auditor = Auditor::new(&document, &config.css, config.bounding_box, &config.locale);
+    let auditor = Auditor::with_options(
+        &document,
+        &config.css,
+        config.bounding_box,
+        &config.locale,
+        config.options.clone(),
+    );
     engine::audit::wcag::WCAGAAA::audit(auditor).await
 }
 
@@ -230,16 +281,33 @@ pub async fn audit(config: &AuditConfig) -> AuditResults {
             website.subscribe(16).unwrap();
         let bounding_box = config.bounding_box;
         let locale = config.locale.clone();
+        let options = config.options.clone();
 
         let audits = tokio::spawn(async move {
             let mut issues: spider::hashbrown::HashMap<String, Vec<Issue>> =
                 spider::hashbrown::HashMap::new();
+            let mut titles: Vec<(String, String)> = Vec::new();
 
             while let Ok(res) = rx2.recv().await {
                 let document = accessibility_scraper::Html::parse_document(&res.get_html()).await;
-                let auditor = Auditor::new(&document, &"", bounding_box, &locale);
+                let url: String = res.get_url().into();
+                if let Some(title) = engine::rules::utils::title::document_title(&document) {
+                    titles.push((url.clone(), title));
+                }
+                let auditor =
+                    Auditor::with_options(&document, &"", bounding_box, &locale, options.clone());
                 let issue = engine::audit::wcag::WCAGAAA::audit(auditor).await;
-                issues.insert(res.get_url().into(), issue);
+                issues.insert(url, issue);
+            }
+
+            // titles can only be compared across pages once the crawl finishes
+            for (url, issue) in engine::rules::utils::title::duplicate_title_issues(
+                titles
+                    .iter()
+                    .map(|(url, title)| (url.as_str(), title.as_str())),
+                &locale,
+            ) {
+                issues.entry(url).or_default().push(issue);
             }
 
             issues
@@ -250,7 +318,13 @@ pub async fn audit(config: &AuditConfig) -> AuditResults {
         AuditResults::Page(audits.await.unwrap_or_default())
     } else {
         let document = accessibility_scraper::Html::parse_document(&config.html).await;
-        let auditor = Auditor::new(&document, &config.css, config.bounding_box, &config.locale);
+        let auditor = Auditor::with_options(
+            &document,
+            &config.css,
+            config.bounding_box,
+            &config.locale,
+            config.options.clone(),
+        );
         AuditResults::Html(engine::audit::wcag::WCAGAAA::audit(auditor).await)
     }
 }
@@ -259,6 +333,12 @@ pub async fn audit(config: &AuditConfig) -> AuditResults {
 #[cfg(not(feature = "tokio"))]
 pub fn audit(config: &AuditConfig) -> Vec<Issue> {
     let document = accessibility_scraper::Html::parse_document(&config.html);
-    let auditor = Auditor::new(&document, &config.css, config.bounding_box, &config.locale);
+    let auditor = Auditor::with_options(
+        &document,
+        &config.css,
+        config.bounding_box,
+        &config.locale,
+        config.options.clone(),
+    );
     engine::audit::wcag::WCAGAAA::audit(auditor)
 }
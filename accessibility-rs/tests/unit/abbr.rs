@@ -0,0 +1,89 @@
+//! Test for abbreviations and acronyms.
+
+use accessibility_rs::{AuditConfig, AuditOptions};
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// abbreviations that are never expanded
+fn _audit_abbreviations_unexpanded() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Abbreviations</title></head>
+       <body>
+            <p>Our site follows WCAG, e.g. for forms, i.e. every input.</p>
+            <p>Read the WCAG notes of the U.S. office.</p>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle3.Guideline3_1.G102,H28")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].message.contains("abbreviations WCAG, U.S. are"));
+    assert_eq!(issues[0].selectors.len(), 2);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// abbreviations expanded on the page
+fn _audit_abbreviations_expanded() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Abbreviations</title></head>
+       <body>
+            <p>The <abbr title="Web Content Accessibility Guidelines">WCAG</abbr> apply to all pages.</p>
+            <p>The World Wide Web Consortium (W3C) publishes the WCAG.</p>
+            <p>Use ARIA (Accessible Rich Internet Applications) sparingly.</p>
+            <p>See <a href="/glossary#nvda">NVDA</a> and <dfn>JAWS</dfn> for screen readers.</p>
+            <p>Set the <code>HTML</code> lang and read chapter IV.</p>
+            <h2>PLEASE READ THE TERMS</h2>
+            <p>The W3C, ARIA, NVDA and JAWS are mentioned again.</p>
+       </body>
+    </html>"###,
+    ));
+
+    assert!(!audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle3.Guideline3_1.G102,H28"));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// abbreviations explained by the team glossary
+fn _audit_abbreviations_glossary() {
+    let html = r###"<html lang="en">
+       <head><title>Abbreviations</title></head>
+       <body><p>Apply for the GST refund by the end of the FY.</p></body>
+    </html>"###;
+    let audit = accessibility_rs::audit(&AuditConfig::basic(html));
+
+    assert_eq!(
+        audit
+            .iter()
+            .filter(|x| x.code == "WCAGAAA.Principle3.Guideline3_1.G102,H28")
+            .count(),
+        1
+    );
+
+    let path = std::env::temp_dir().join(format!(
+        "accessibility-rs-glossary-{}.txt",
+        std::process::id()
+    ));
+    std::fs::write(&path, "# finance terms\nGST: goods and services tax\nFY\n").unwrap();
+    let options = AuditOptions::default().with_glossary_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(options.glossary, ["GST", "FY"]);
+
+    let audit = accessibility_rs::audit(&AuditConfig {
+        html,
+        options,
+        ..Default::default()
+    });
+
+    assert!(!audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle3.Guideline3_1.G102,H28"));
+}
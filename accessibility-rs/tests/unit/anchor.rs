@@ -79,3 +79,93 @@ fn _audit_img_alt_matches_text_anchor() {
 
     assert_eq!(valid, false)
 }
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// anchor text is ambiguous without a link context
+fn _audit_ambiguous_link_text() {
+    let markup = html! {
+        html lang="en" {
+            body {
+                div { a href="/pricing" { "Click here" } }
+                p { "Compare the plans on our " a href="/plans" { "click here" } " page." }
+            }
+        }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let context_issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle2.Guideline2_4.H77,H78,H79,H80,H81")
+        .count();
+    let link_only_issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle2.Guideline2_4.H30")
+        .count();
+
+    assert_eq!(context_issues, 1);
+    // the ambiguous texts and the same text pointing to different pages
+    assert_eq!(link_only_issues, 3);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// anchor text is ambiguous in the language of the document
+fn _audit_ambiguous_link_text_locale() {
+    let markup = html! {
+        html lang="fr-CA" {
+            body {
+                div { a href="/tarifs" { "Cliquez ici" } }
+            }
+        }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let valid = !audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle2.Guideline2_4.H77,H78,H79,H80,H81");
+
+    assert_eq!(valid, false)
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// links inside text need a non color cue or 3:1 contrast with the text and a cue on focus.
+fn _audit_link_distinguishable() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+    <head>
+        <title>Terms</title>
+        <style>
+            body { color: #000000; }
+            .dark { text-decoration: none; color: #0000ee; }
+            .light, .focus, .bold, .border { text-decoration: none; color: #5a9bd5; }
+            .focus:focus { text-decoration: underline; }
+            .bold { font-weight: bold; }
+            .border { border-bottom: 1px solid #5a9bd5; }
+        </style>
+    </head>
+    <body>
+        <nav><a class="dark" href="/">Home</a></nav>
+        <p>Read the <a class="dark" href="/terms">terms</a> first.</p>
+        <p>Read the <a href="/privacy">privacy policy</a> first.</p>
+        <p>Read the <a class="light" href="/cookies">cookie policy</a> first.</p>
+        <p>Read the <a class="focus" href="/security">security policy</a> first.</p>
+        <p>Read the <a class="bold" href="/legal">legal notice</a> first.</p>
+        <p>Read the <a class="border" href="/help">help</a> first.</p>
+    </body>
+</html>"###,
+    ));
+    let color_only = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.F73")
+        .collect::<Vec<_>>();
+    let focus_cue = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G183")
+        .collect::<Vec<_>>();
+
+    assert_eq!(color_only.len(), 1);
+    // the dark link only and the light link without a focus cue
+    assert!(color_only[0].message.contains("2.23:1"));
+    assert_eq!(focus_cue.len(), 1);
+    assert!(focus_cue[0].message.contains("7.08:1"));
+}
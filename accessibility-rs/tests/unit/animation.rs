@@ -0,0 +1,108 @@
+//! Test for moving content and animations.
+
+use accessibility_rs::AuditConfig;
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// marquee elements cannot be paused
+fn _audit_marquee() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Marquee</title></head>
+       <body><marquee>Breaking news</marquee></body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| {
+            x.code == "WCAGAAA.Principle2.Guideline2_2.F16"
+                || x.code == "WCAGAAA.Principle2.Guideline2_3.C39"
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].message.starts_with("Marquee elements"));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// infinite animations without a reduced motion alternative
+fn _audit_infinite_animation() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head>
+            <title>Animation</title>
+            <style>
+                .spinner { animation: spin 2s linear infinite; }
+                .pulse { animation-name: pulse; animation-duration: 500ms; animation-iteration-count: 3; }
+                .hidden { display: none; animation: spin 1s infinite; }
+            </style>
+       </head>
+       <body>
+            <div class="spinner">Loading</div>
+            <div class="pulse">New</div>
+            <div class="hidden">Hidden</div>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| {
+            x.code == "WCAGAAA.Principle2.Guideline2_2.F16"
+                || x.code == "WCAGAAA.Principle2.Guideline2_3.C39"
+        })
+        .collect::<Vec<_>>();
+    let infinite = issues
+        .iter()
+        .filter(|x| x.message.contains("repeats infinitely"))
+        .count();
+    let reduced = issues
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle2.Guideline2_3.C39")
+        .map(|x| x.selectors.len())
+        .sum::<usize>();
+
+    assert_eq!(infinite, 1);
+    assert_eq!(reduced, 2);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// animations and transitions removed with a prefers-reduced-motion media query
+fn _audit_reduced_motion() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head>
+            <title>Reduced motion</title>
+            <style>
+                .spinner { animation: spin 2s linear 5; }
+                .drawer { transition: transform 0.3s ease-in-out; }
+                .fade { transition: opacity 0.3s; }
+                @media (prefers-reduced-motion: reduce) {
+                    .spinner { animation: none; }
+                }
+                @media (prefers-reduced-motion: no-preference) {
+                    .menu { transition: left 200ms; }
+                }
+            </style>
+       </head>
+       <body>
+            <div class="spinner">Loading</div>
+            <div class="drawer">Drawer</div>
+            <div class="fade">Fade</div>
+            <div class="menu">Menu</div>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| {
+            x.code == "WCAGAAA.Principle2.Guideline2_2.F16"
+                || x.code == "WCAGAAA.Principle2.Guideline2_3.C39"
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].message.contains("CSS transition"));
+    assert_eq!(issues[0].selectors.len(), 1);
+}
@@ -0,0 +1,60 @@
+//! Test for button elements and form submit buttons.
+
+use accessibility_rs::AuditConfig;
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// forms with implicit and explicit submit controls
+fn _audit_form_submit_button() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Checkout</title></head>
+       <body>
+            <form action="/a"><label>Email <input name="email"></label><button>Send</button></form>
+            <form action="/b"><button type="submit">Send</button></form>
+            <form action="/c"><button type="SUBMIT">Send</button></form>
+            <form action="/d"><input type="submit"></form>
+            <form action="/e"><input type="image" src="/go.png" alt="Go"></form>
+            <form action="/f" id="search"><input name="q" aria-label="Search"></form>
+            <button form="search">Search</button>
+            <form action="/g"><button type="button">Check</button><input type="reset"></form>
+            <form action="/h"><input name="q" aria-label="Query"></form>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle3.Guideline3_2.H32")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].selectors.len(), 2);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// buttons without an accessible name
+fn _audit_button_name() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Checkout</title></head>
+       <body>
+            <button><i class="icon-close"></i></button>
+            <button><svg><path d="M0 0h10v10z"/></svg></button>
+            <button><img src="/trash.png" alt=""></button>
+            <button>Save</button>
+            <button aria-label="Close"><i class="icon-close"></i></button>
+            <button><img src="/trash.png" alt="Delete"></button>
+            <button title="Print"><i class="icon-print"></i></button>
+            <button hidden></button>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle4.Guideline4_1.H91")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].selectors.len(), 3);
+}
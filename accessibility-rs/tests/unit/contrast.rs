@@ -20,3 +20,252 @@ fn _audit_contrast_text_anchor() {
 
     assert_eq!(valid, false)
 }
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// text inherits the color through the ancestors on a semi transparent background.
+fn _audit_contrast_composited_background() {
+    let markup = html! {
+        body style="color: #777777;" {
+            div style="background-color: rgba(0, 0, 0, 0.5);" {
+                section { p { "Low contrast text" } }
+            }
+        }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let issue = audit
+        .iter()
+        .find(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G18");
+
+    assert!(issue.is_some());
+    assert!(issue.unwrap().message.contains("#7f7f7f"));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// large text requires a lower contrast ratio than normal text.
+fn _audit_contrast_large_text() {
+    let markup = html! {
+        body style="color: #949494;" {
+            h1 { "Large heading" }
+            p { "Normal text" }
+            span style="font-size: 14pt; font-weight: bold;" { "Bold text" }
+            span style="font-size: 14pt;" { "Regular text" }
+        }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let minimum = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G18")
+        .count();
+    let enhanced = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G17")
+        .count();
+
+    assert_eq!(minimum, 2);
+    assert_eq!(enhanced, 4);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// headings and strong text use the default sizes and weights of the browser.
+fn _audit_contrast_heading_defaults() {
+    let markup = html! {
+        body style="color: #949494;" {
+            h2 { "Section" }
+            h3 { "Subsection" }
+            h4 { "Small heading" }
+            p style="font-size: 14pt;" { strong { "Important" } }
+            p { "Normal text" }
+        }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let minimum = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G18")
+        .flat_map(|x| x.selectors.clone())
+        .count();
+
+    assert_eq!(minimum, 2);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// form control boundaries and focus outlines need 3:1 contrast.
+fn _audit_contrast_non_text() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+    <head>
+        <title>Sign up</title>
+        <style>
+            .faint { border-style: solid; border-width: 1px; border-color: #dddddd; }
+            .strong { border-style: solid; border-width: 1px; border-color: #767676; }
+            .flat { background-color: #eeeeee; }
+            a:focus { outline: 2px solid #eeeeee; }
+        </style>
+    </head>
+    <body>
+        <label>Email <input class="faint" type="email" name="email"></label>
+        <label>Name <input class="strong" type="text" name="name"></label>
+        <label>Phone <input type="tel" name="phone"></label>
+        <button class="flat" type="submit">Sign up</button>
+        <a href="/terms">Terms</a>
+    </body>
+</html>"###,
+    ));
+    let boundaries = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G209")
+        .count();
+    let focus = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G195")
+        .count();
+
+    assert_eq!(boundaries, 2);
+    assert_eq!(focus, 1);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// text on background images and gradients is noticed for review instead of measured.
+fn _audit_contrast_background_image() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+    <head>
+        <title>Welcome</title>
+        <style>
+            .hero { background: #ffffff url("hero.jpg") no-repeat; color: #eeeeee; }
+            .banner { background-image: linear-gradient(to right, #000000, #ffffff); color: #eeeeee; }
+            .card { background-color: #000000; color: #ffffff; }
+        </style>
+    </head>
+    <body>
+        <header class="hero"><h1>Welcome</h1><div class="card"><p>Read more</p></div></header>
+        <div class="banner"><p>Sale today</p></div>
+    </body>
+</html>"###,
+    ));
+    let errors = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G18" && x.issue_type == "error")
+        .count();
+    let notices = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G18" && x.issue_type == "notice")
+        .count();
+
+    assert_eq!(errors, 0);
+    assert_eq!(notices, 2);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// contrast failures recommend the nearest text and background colors that pass.
+fn _audit_contrast_recommendation() {
+    use accessibility_rs::engine::rules::utils::contrast::{contrast_ratio, suggest_color};
+    use rgb::RGB8;
+
+    let markup = html! {
+        body style="color: #3355aa; background-color: #7799cc;" {
+            p { "Low contrast text" }
+        }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let issue = audit
+        .iter()
+        .find(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G18")
+        .expect("contrast issue");
+
+    assert!(issue
+        .message
+        .contains("Recommendation: change text colour to #"));
+    assert!(issue.message.contains("change background to #"));
+
+    let foreground = RGB8::new(0x33, 0x55, 0xaa);
+    let background = RGB8::new(0x77, 0x99, 0xcc);
+    let text = suggest_color(foreground, background, 4.5).expect("text suggestion");
+
+    let suggested = suggest_color(background, foreground, 4.5).expect("background suggestion");
+
+    assert!(contrast_ratio(text, background) >= 4.5);
+    assert!(contrast_ratio(foreground, suggested) >= 4.5);
+    // the hue is kept by darkening the blue
+    assert!(text.b > text.r && text.b > text.g);
+    assert!(issue
+        .message
+        .contains(&format!("#{:02x}{:02x}{:02x}", text.r, text.g, text.b)));
+    assert!(issue.message.contains(&format!(
+        "#{:02x}{:02x}{:02x}",
+        suggested.r, suggested.g, suggested.b
+    )));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// hidden and visually hidden text is not measured while aria hidden text is still painted.
+fn _audit_contrast_hidden_text() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+    <head>
+        <title>Hidden</title>
+        <style>
+            body { color: #eeeeee; }
+            .none { display: none; }
+            .invisible { visibility: hidden; }
+            .shown { visibility: visible; }
+            .transparent { opacity: 0; }
+            .sr-only { position: absolute; width: 1px; height: 1px; overflow: hidden; clip: rect(0, 0, 0, 0); }
+            .clipped { clip-path: inset(50%); }
+            .off-screen { position: absolute; left: -9999px; }
+        </style>
+    </head>
+    <body>
+        <div class="none"><p>Display none</p></div>
+        <p hidden>Hidden attribute</p>
+        <div class="invisible"><p>Visibility hidden</p><p class="shown">Visible again</p></div>
+        <p class="transparent">Transparent</p>
+        <span class="sr-only">Visually hidden</span>
+        <span class="clipped">Clipped</span>
+        <a class="off-screen" href="#main">Skip to content</a>
+        <p aria-hidden="true">Aria hidden</p>
+    </body>
+</html>"###,
+    ));
+    let measured = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G18")
+        .count();
+
+    // the text revealed with visibility: visible and the aria hidden text
+    assert_eq!(measured, 2);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// style elements without a type or with the css type apply to the page
+fn _audit_contrast_style_element_type() {
+    for (type_attr, applied) in [
+        ("", true),
+        (r#" type="""#, true),
+        (r#" type="text/css""#, true),
+        (r#" type="text/plain""#, false),
+    ] {
+        let html = format!(
+            r###"<html lang="en">
+    <head>
+        <title>Styles</title>
+        <style{type_attr}>p {{ color: #cccccc; }}</style>
+    </head>
+    <body><p>Light text</p></body>
+ </html>"###
+        );
+        let audit = accessibility_rs::audit(&AuditConfig::basic(&html));
+        let valid = !audit
+            .iter()
+            .any(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G18");
+
+        assert_eq!(valid, !applied, "{type_attr}");
+    }
+}
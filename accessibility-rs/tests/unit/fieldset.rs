@@ -23,3 +23,88 @@ fn _audit_missing_fieldset_legend() {
 
     assert_eq!(valid, false)
 }
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// radio buttons sharing a name are not grouped
+fn _audit_radio_group_missing_fieldset() {
+    let m = html! {
+        form {
+            input type="radio" id="yes" name="subscribe" value="yes";
+            label for="yes" { "Yes" };
+            input type="radio" id="no" name="subscribe" value="no";
+            label for="no" { "No" };
+        }
+    };
+
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&m.into_string()));
+
+    let valid = !audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H71");
+
+    assert_eq!(valid, false);
+
+    let m = html! {
+        form {
+            div role="radiogroup" aria-label="Subscribe to the newsletter" {
+                input type="radio" id="yes" name="subscribe" value="yes";
+                label for="yes" { "Yes" };
+                input type="radio" id="no" name="subscribe" value="no";
+                label for="no" { "No" };
+            }
+        }
+    };
+
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&m.into_string()));
+
+    let valid = !audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H71");
+
+    assert_eq!(valid, true);
+
+    let m = html! {
+        form {
+            div role="radiogroup" aria-label=" " {
+                input type="radio" id="yes" name="subscribe" value="yes";
+                label for="yes" { "Yes" };
+                input type="radio" id="no" name="subscribe" value="no";
+                label for="no" { "No" };
+            }
+        }
+    };
+
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&m.into_string()));
+
+    let valid = !audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H71");
+
+    assert_eq!(valid, false)
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// fieldset contains radio buttons of different groups
+fn _audit_fieldset_mixed_radio_names() {
+    let m = html! {
+        fieldset {
+            legend { "Shipping" };
+            input type="radio" id="standard" name="shipping" value="standard";
+            label for="standard" { "Standard" };
+            input type="radio" id="express" name="ship" value="express";
+            label for="express" { "Express" };
+        }
+    };
+
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&m.into_string()));
+
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H71")
+        .count();
+
+    // two single radio groups and the fieldset mixing names
+    assert_eq!(issues, 3)
+}
@@ -1 +1,69 @@
 //! Test generic html elements
+
+use accessibility_rs::engine::rules::utils::nodes::get_unique_selector;
+use accessibility_scraper::{Html, Selector};
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// the selectors of elements without an id walk up the parents by position
+fn _unique_selector_nth_child() {
+    let document = Html::parse_document(
+        r###"<html lang="en">
+    <head><title>Gallery</title></head>
+    <body>
+        <ul><li><img src="a.png"></li><li><img src="b.png"></li></ul>
+        <div id="gallery"><p><img src="c.png"></p><p><span><img src="d.png"></span></p></div>
+        <img class="hero" src="e.png">
+        <section><h2>Title</h2></section>
+    </body>
+ </html>"###,
+    );
+    let selector = Selector::parse("img, h2, ul").unwrap();
+    let selectors = document
+        .select(&selector)
+        .map(|ele| get_unique_selector(&ele))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        selectors,
+        [
+            "ul",
+            "ul>:nth-child(1)>:nth-child(1)",
+            "ul>:nth-child(2)>:nth-child(1)",
+            "#gallery>:nth-child(1)>:nth-child(1)",
+            "span>:nth-child(1)",
+            "body>:nth-child(6)",
+            "h2"
+        ]
+    );
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// the selector of a deeply nested element is built without overflowing the stack
+fn _unique_selector_deep_nesting() {
+    let depth = 1000;
+    // a small stack that recursing once per ancestor would overflow
+    let selector = std::thread::Builder::new()
+        .stack_size(256 * 1024)
+        .spawn(move || {
+            let document = Html::parse_document(&format!(
+                r###"<html lang="en"><head><title>Nested</title></head><body><img src="a.png">{}<img src="b.png">{}</body></html>"###,
+                "<div>".repeat(depth),
+                "</div>".repeat(depth)
+            ));
+            let selector = Selector::parse("img").unwrap();
+
+            document
+                .select(&selector)
+                .next_back()
+                .map(|ele| get_unique_selector(&ele))
+                .unwrap_or_default()
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    assert!(selector.starts_with("body>:nth-child(2)>:nth-child(1)>"));
+    assert_eq!(selector.matches(">:nth-child(1)").count(), depth);
+}
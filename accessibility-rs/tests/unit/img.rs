@@ -81,3 +81,106 @@ fn _audit_form_submit_img_has_alt_and_title() {
 
     assert_eq!(valid, false)
 }
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// img alt text that is a file name, url or placeholder
+fn _audit_img_alt_text_quality() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Gallery</title></head>
+       <body>
+            <img src="/a.jpg" alt="IMG_1234.jpg">
+            <img src="/b.jpg" alt="DSC01234">
+            <img src="/c.jpg" alt="https://example.com/c.jpg">
+            <img src="/d.jpg" alt="photo">
+            <img src="/e.jpg" alt="Picture of a lighthouse at dusk">
+            <img src="/f.jpg" alt="Image offsets on the chart">
+            <img src="/g.jpg" alt="Lighthouse at dusk">
+            <img src="/h.jpg" alt="">
+            <img src="/i.jpg" alt="A380">
+            <img src="/j.jpg" alt="F150">
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_1.G94")
+        .collect::<Vec<_>>();
+    let count = |start: &str| {
+        issues
+            .iter()
+            .find(|x| x.message.starts_with(start))
+            .map(|x| x.selectors.len())
+    };
+
+    assert_eq!(issues.len(), 3);
+    assert_eq!(
+        count("Img element alt text appears to be a file name"),
+        Some(2)
+    );
+    assert_eq!(count("Img element alt text appears to be a URL"), Some(1));
+    assert_eq!(count("Img element alt text is a placeholder"), Some(2));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// img alt text that repeats the caption, is too long or describes a spacer
+fn _audit_img_alt_text_context() {
+    let long = "A lighthouse ".repeat(15);
+    let html = format!(
+        r###"<html lang="en">
+       <head><title>Gallery</title></head>
+       <body>
+            <figure><img src="/a.jpg" alt="Lighthouse at dusk"><figcaption>Lighthouse at dusk.</figcaption></figure>
+            <figure><img src="/b.jpg" alt="A white tower"><figcaption>Lighthouse at dusk</figcaption></figure>
+            <img src="/c.jpg" alt="{long}">
+            <img src="/images/spacer.gif" alt="spacing">
+            <img src="/d.gif" width="1" height="1" alt="tracking">
+            <img src="/images/spacer.gif" alt="">
+       </body>
+    </html>"###
+    );
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&html));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_1.G94")
+        .collect::<Vec<_>>();
+    let count = |start: &str| {
+        issues
+            .iter()
+            .find(|x| x.message.starts_with(start))
+            .map(|x| x.selectors.len())
+    };
+
+    assert_eq!(issues.len(), 3);
+    assert_eq!(
+        count("Img element alt text duplicates the caption"),
+        Some(1)
+    );
+    assert_eq!(count("Img element alt text is longer"), Some(1));
+    assert_eq!(count("Img element appears to be a spacer"), Some(2));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// img alt text inside a link that repeats the text of the link beside it
+fn _audit_img_alt_adjacent_link() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Gallery</title></head>
+       <body>
+            <a href="/products"><img src="/a.png" alt="Products"></a> <a href="/products">Products</a>
+            <a href="/about"><img src="/b.png" alt="Our team"></a> <a href="/about">About us</a>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_1.H2")
+        .collect::<Vec<_>>();
+
+    assert!(issues.iter().any(
+        |x| x.message.contains("duplicates the content of a text link") && x.selectors.len() == 1
+    ));
+}
@@ -1,5 +1,6 @@
 //! Test for input elements.
 use accessibility_rs::AuditConfig;
+use maud::html;
 
 #[test]
 #[cfg(not(feature = "tokio"))]
@@ -26,3 +27,92 @@ fn _audit_input_valid_name() {
 
     assert_eq!(valid, false)
 }
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// input autocomplete contains invalid tokens
+fn _audit_input_autocomplete_faulty() {
+    let markup = html! {
+        form {
+            label for="fname" { "First name" };
+            input type="text" id="fname" autocomplete="section-blue shipping given-name";
+            label for="lname" { "Last name" };
+            input type="text" id="lname" autocomplete="family-name shipping";
+        }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let faulty: Vec<_> = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H98")
+        .collect();
+
+    assert_eq!(faulty.len(), 1);
+    assert_eq!(faulty[0].selectors, vec!["#lname"]);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// input autocomplete does not belong to the control group
+fn _audit_input_autocomplete_control_group() {
+    let markup = html! {
+        input type="number" id="contact" autocomplete="work email";
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let valid = !audit.iter().any(|x| {
+        x.code == "WCAGAAA.Principle1.Guideline1_3.H98" && x.message.contains("Email control group")
+    });
+
+    assert_eq!(valid, false);
+
+    let markup = html! {
+        input type="email" id="contact" autocomplete="work email webauthn";
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let valid = !audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H98");
+
+    assert_eq!(valid, true)
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// personal data input has autocomplete turned off
+fn _audit_input_autocomplete_off() {
+    let markup = html! {
+        input type="email" id="email" autocomplete="off";
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let valid = !audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H98" && x.issue_type == "notice");
+
+    assert_eq!(valid, false)
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// personal data hints match whole words of the control name or id
+fn _audit_input_autocomplete_off_hints() {
+    for (name, personal) in [
+        ("user_name", true),
+        ("billingAddress", true),
+        ("e-mail", true),
+        ("phone number", true),
+        ("tel", true),
+        ("title", false),
+        ("filename", false),
+        ("subtitle", false),
+        ("telescope", false),
+    ] {
+        let markup = html! {
+            input type="text" name=(name) autocomplete="off";
+        };
+        let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+        let flagged = audit
+            .iter()
+            .any(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H98" && x.issue_type == "notice");
+
+        assert_eq!(flagged, personal, "{name}");
+    }
+}
@@ -0,0 +1,142 @@
+//! Test for keyboard access.
+
+use accessibility_rs::AuditConfig;
+use maud::html;
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// mouse operated element that cannot be reached with the keyboard
+fn _audit_keyboard_not_focusable() {
+    let markup = html! {
+        div onclick="save()" { "Save" }
+        span role="button" { "Cancel" }
+        div role="button" tabindex="0" onkeydown="send(event)" onclick="send()" { "Send" }
+        button onclick="reset()" { "Reset" }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle2.Guideline2_1.F54,F42")
+        .flat_map(|x| x.selectors.clone())
+        .count();
+
+    assert_eq!(issues, 2)
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// mouse event handlers without keyboard equivalents
+fn _audit_keyboard_mouse_only_handlers() {
+    let markup = html! {
+        div tabindex="0" onmouseover="show()" { "Menu" }
+        div tabindex="0" onmouseover="show()" onfocus="show()" { "Menu" }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle2.Guideline2_1.SCR20")
+        .flat_map(|x| x.selectors.clone())
+        .count();
+
+    assert_eq!(issues, 1)
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// tabindex changes the tab order or focuses non-interactive content
+fn _audit_keyboard_tabindex() {
+    let markup = html! {
+        a href="/" tabindex="2" { "Home" }
+        p tabindex="0" { "Opening hours" }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let positive = !audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle2.Guideline2_4.F44");
+    let non_interactive = !audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle2.Guideline2_4.H4");
+
+    assert_eq!(positive, false);
+    assert_eq!(non_interactive, false);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// interactive content nested inside other interactive content
+fn _audit_nested_interactive() {
+    let markup = html! {
+        a href="/cart" { button { "Checkout" } }
+        button { input type="checkbox"; "Remember" }
+        div role="button" tabindex="0" { span tabindex="0" { "Open" } }
+        label { input type="checkbox"; "Subscribe" }
+    };
+    let audit = accessibility_rs::audit(&AuditConfig::basic(&markup.into_string()));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle4.Guideline4_1.H91")
+        .filter(|x| x.message.contains("nested"))
+        .count();
+
+    assert_eq!(issues, 3)
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// focus outline removed without another focus indicator
+fn _audit_focus_outline_removed() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+    <head>
+        <title>Focus styles</title>
+        <style>
+            a:focus { outline: none; }
+            .card:focus-visible { outline: 0; box-shadow: 0 0 0 3px blue; }
+            button:focus { outline: none; background-color: yellow; }
+        </style>
+    </head>
+    <body>
+        <a href="/pricing">Pricing</a>
+        <a class="card" href="/plans">Plans</a>
+        <button>Send</button>
+    </body>
+ </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle2.Guideline2_4.F78")
+        .flat_map(|x| x.selectors.clone())
+        .count();
+
+    assert_eq!(issues, 1)
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// focus styles of class names with escaped colons and attribute selectors
+fn _audit_focus_outline_escaped_class() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+    <head>
+        <title>Focus styles</title>
+        <style>
+            .sm\:focus\:outline-none:focus { outline: none; }
+            .sm\:focus\:ring:focus-visible { outline: 0; box-shadow: 0 0 0 3px blue; }
+            input[type="search"]:focus { outline: none; }
+        </style>
+    </head>
+    <body>
+        <a class="sm:focus:outline-none" href="/pricing">Pricing</a>
+        <a class="sm:focus:outline-none sm:focus:ring" href="/plans">Plans</a>
+        <input type="search" aria-label="Search">
+    </body>
+ </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle2.Guideline2_4.F78")
+        .flat_map(|x| x.selectors.clone())
+        .count();
+
+    assert_eq!(issues, 2)
+}
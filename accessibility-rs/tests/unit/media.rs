@@ -0,0 +1,162 @@
+//! Test for media.
+
+use accessibility_rs::AuditConfig;
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// video without captions, descriptions and controls
+fn _audit_video_missing_tracks() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Video</title></head>
+       <body>
+            <video src="intro.mp4" autoplay></video>
+       </body>
+    </html>"###,
+    ));
+    let mut codes = audit
+        .iter()
+        .filter(|x| {
+            x.code.starts_with("WCAGAAA.Principle1.Guideline1_2")
+                || x.code.starts_with("WCAGAAA.Principle1.Guideline1_4.F")
+        })
+        .map(|x| x.code.as_str())
+        .collect::<Vec<_>>();
+    codes.sort();
+
+    assert_eq!(
+        codes,
+        [
+            "WCAGAAA.Principle1.Guideline1_2.G69,G78,G173,G8",
+            "WCAGAAA.Principle1.Guideline1_2.G78,G173,G8",
+            "WCAGAAA.Principle1.Guideline1_2.G87,G93",
+            "WCAGAAA.Principle1.Guideline1_4.F93",
+        ]
+    );
+    assert!(audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.F93")
+        .all(|x| x.issue_type == "notice"));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// video with captions and descriptions tracks
+fn _audit_video_tracks() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Video</title></head>
+       <body>
+            <video src="intro.mp4" autoplay muted>
+                <track kind="captions" src="intro.en.vtt" srclang="en" label="English">
+                <track kind="descriptions" src="intro.desc.vtt" srclang="en" label="English descriptions">
+            </video>
+       </body>
+    </html>"###,
+    ));
+    let mut codes = audit
+        .iter()
+        .filter(|x| {
+            x.code.starts_with("WCAGAAA.Principle1.Guideline1_2")
+                || x.code.starts_with("WCAGAAA.Principle1.Guideline1_4.F")
+        })
+        .map(|x| x.code.as_str())
+        .collect::<Vec<_>>();
+    codes.sort();
+
+    assert!(codes.is_empty(), "{codes:?}");
+
+    // a transcript covers 1.2.3 but not the audio description of 1.2.5
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Video</title></head>
+       <body>
+            <div>
+                <video src="intro.mp4" controls autoplay>
+                    <track src="intro.en.vtt">
+                </video>
+                <a href="/intro">Read the transcript</a>
+            </div>
+       </body>
+    </html>"###,
+    ));
+    let mut codes = audit
+        .iter()
+        .filter(|x| {
+            x.code.starts_with("WCAGAAA.Principle1.Guideline1_2")
+                || x.code.starts_with("WCAGAAA.Principle1.Guideline1_4.F")
+        })
+        .map(|x| x.code.as_str())
+        .collect::<Vec<_>>();
+    codes.sort();
+
+    assert_eq!(
+        codes,
+        [
+            "WCAGAAA.Principle1.Guideline1_2.G78,G173,G8",
+            "WCAGAAA.Principle1.Guideline1_2.H95",
+            "WCAGAAA.Principle1.Guideline1_2.H95",
+            "WCAGAAA.Principle1.Guideline1_4.F23",
+        ]
+    );
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// audio with and without a transcript
+fn _audit_audio_transcript() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="fr">
+       <head><title>Audio</title></head>
+       <body>
+            <audio src="episode.mp3" controls></audio>
+            <p><audio src="interview.mp3" controls></audio><a href="/interview">Lire la transcription</a></p>
+       </body>
+    </html>"###,
+    ));
+    let mut codes = audit
+        .iter()
+        .filter(|x| {
+            x.code.starts_with("WCAGAAA.Principle1.Guideline1_2")
+                || x.code.starts_with("WCAGAAA.Principle1.Guideline1_4.F")
+        })
+        .map(|x| x.code.as_str())
+        .collect::<Vec<_>>();
+    codes.sort();
+
+    assert_eq!(codes, ["WCAGAAA.Principle1.Guideline1_2.G158"]);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// legacy embed and object media need review
+fn _audit_embedded_media() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Embedded</title></head>
+       <body>
+            <embed src="movie.swf" type="application/x-shockwave-flash">
+            <object data="https://www.youtube.com/v/intro">Introduction</object>
+            <object data="chart.png" type="image/png">Sales chart</object>
+            <object data="report.pdf" type="application/pdf">Annual report</object>
+       </body>
+    </html>"###,
+    ));
+    let mut codes = audit
+        .iter()
+        .filter(|x| {
+            x.code.starts_with("WCAGAAA.Principle1.Guideline1_2")
+                || x.code.starts_with("WCAGAAA.Principle1.Guideline1_4.F")
+        })
+        .map(|x| x.code.as_str())
+        .collect::<Vec<_>>();
+    codes.sort();
+
+    assert_eq!(
+        codes,
+        [
+            "WCAGAAA.Principle1.Guideline1_2.G87,G93",
+            "WCAGAAA.Principle1.Guideline1_2.G87,G93",
+        ]
+    );
+}
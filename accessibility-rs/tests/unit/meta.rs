@@ -219,3 +219,265 @@ fn _xml_lang_incorrect_format() {
 
     assert_eq!(valid, false);
 }
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// lang attributes are validated as BCP 47 language tags
+fn _audit_lang_language_tags() {
+    for lang in [
+        "en-US",
+        "zh-Hant-TW",
+        "sgn-BE-FR",
+        "de-CH-1996",
+        "yue-HK",
+        "qaa",
+        "x-klingon",
+    ] {
+        let html = format!(
+            r###"<html lang="{lang}"><head><title>Language</title></head><body><p>Hello</p></body></html>"###
+        );
+        let audit = accessibility_rs::audit(&AuditConfig::basic(&html));
+        let valid = !audit
+            .iter()
+            .any(|x| x.code == "WCAGAAA.Principle3.Guideline3_1.H57");
+
+        assert_eq!(valid, true, "{lang}");
+    }
+
+    for lang in ["en_US", "eng", "xyz", "abc", "english-us", "en-US-"] {
+        let html = format!(
+            r###"<html lang="{lang}"><head><title>Language</title></head><body><p>Hello</p></body></html>"###
+        );
+        let audit = accessibility_rs::audit(&AuditConfig::basic(&html));
+        let valid = !audit
+            .iter()
+            .any(|x| x.code == "WCAGAAA.Principle3.Guideline3_1.H57");
+
+        assert_eq!(valid, false, "{lang}");
+    }
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// lang and xml:lang specify different languages
+fn _audit_lang_mismatch() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html xmlns="http://www.w3.org/1999/xhtml" lang="en" xml:lang="fr">
+        <head><title>Language</title></head>
+        <body><p lang="de" xml:lang="de-AT">Hallo</p></body>
+    </html>"###,
+    ));
+    let mismatch = audit
+        .iter()
+        .filter(|x| x.message.contains("specify different languages"))
+        .map(|x| x.code.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        mismatch,
+        [
+            "WCAGAAA.Principle3.Guideline3_1.H57",
+            "WCAGAAA.Principle3.Guideline3_1.H58"
+        ]
+    );
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// the language of parts of the page is well-formed
+fn _audit_lang_of_parts() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+        <head><title>Language</title></head>
+        <body>
+            <p lang="fr-CA">Bonjour</p>
+            <p lang="fr_CA">Bonjour</p>
+            <p lang="">Unknown</p>
+        </body>
+    </html>"###,
+    ));
+    let parts = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle3.Guideline3_1.H58")
+        .count();
+
+    assert_eq!(parts, 1);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// meta viewport prevents users from zooming
+fn _audit_meta_viewport_zoom() {
+    let html = r###"<html lang="en">
+       <head>
+          <title>Zoom</title>
+          <meta name="viewport" content="width=device-width, initial-scale=1, maximum-scale=1, user-scalable=no">
+       </head>
+       <body><p>Pinch to zoom</p></body>
+    </html>"###;
+    let audit = accessibility_rs::audit(&AuditConfig::basic(html));
+    let zoom = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G142")
+        .count();
+
+    assert_eq!(zoom, 2);
+
+    let html = r###"<html lang="en">
+       <head>
+          <title>Zoom</title>
+          <meta name="viewport" content="width=device-width, maximum-scale=3">
+       </head>
+       <body><p>Pinch to zoom</p></body>
+    </html>"###;
+    let audit = accessibility_rs::audit(&AuditConfig::basic(html));
+    let valid = !audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G142");
+
+    assert_eq!(valid, true);
+
+    // stricter audits require zooming up to 500 percent
+    let audit = accessibility_rs::audit(&AuditConfig {
+        html,
+        options: accessibility_rs::AuditOptions {
+            viewport_maximum_scale: 5.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let valid = !audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G142");
+
+    assert_eq!(valid, false);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// maximum-scale keywords translate to zoom factors
+fn _audit_meta_viewport_maximum_scale_keywords() {
+    for (scale, blocked) in [
+        ("no", true),
+        ("yes", true),
+        ("auto", true),
+        ("device-width", false),
+        ("2", false),
+    ] {
+        let html = format!(
+            r###"<html lang="en">
+       <head>
+          <title>Zoom</title>
+          <meta name="viewport" content="width=device-width, maximum-scale={scale}">
+       </head>
+       <body><p>Pinch to zoom</p></body>
+    </html>"###
+        );
+        let audit = accessibility_rs::audit(&AuditConfig::basic(&html));
+        let zoom = audit
+            .iter()
+            .any(|x| x.code == "WCAGAAA.Principle1.Guideline1_4.G142");
+
+        assert_eq!(zoom, blocked, "{scale}");
+    }
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// the title does not describe the document
+fn _audit_title_purpose() {
+    for (title, expected) in [
+        (
+            "   ",
+            Some(("WCAGAAA.Principle2.Guideline2_4.H25", "error")),
+        ),
+        (
+            "Untitled Document",
+            Some(("WCAGAAA.Principle2.Guideline2_4.F25", "warning")),
+        ),
+        (
+            "Home page",
+            Some(("WCAGAAA.Principle2.Guideline2_4.F25", "warning")),
+        ),
+        (
+            "index.html",
+            Some(("WCAGAAA.Principle2.Guideline2_4.F25", "error")),
+        ),
+        (
+            &"Accessible pricing plans ".repeat(8),
+            Some(("WCAGAAA.Principle2.Guideline2_4.H25", "warning")),
+        ),
+        ("Pricing plans - Example Store", None),
+    ] {
+        let html = format!(
+            r###"<html lang="en">
+       <head><title>{title}</title></head>
+       <body><p>Title</p></body>
+    </html>"###
+        );
+        let audit = accessibility_rs::audit(&AuditConfig::basic(&html));
+        let codes = audit
+            .iter()
+            .filter(|x| {
+                x.code.starts_with("WCAGAAA.Principle2.Guideline2_4.") && x.context == "title"
+            })
+            .map(|x| (x.code.as_str(), x.issue_type))
+            .collect::<Vec<_>>();
+
+        assert_eq!(codes, Vec::from_iter(expected), "{title}");
+    }
+}
+
+#[test]
+/// titles shared by many pages of a website
+fn _audit_duplicate_titles() {
+    use accessibility_rs::engine::rules::utils::title::duplicate_title_issues;
+
+    let titles = [
+        ("https://example.com/", "Example Store"),
+        ("https://example.com/about", "Example Store"),
+        ("https://example.com/pricing", "Example Store"),
+        ("https://example.com/pricing", "Example Store"),
+        ("https://example.com/contact", "Contact - Example Store"),
+    ];
+    let issues = duplicate_title_issues(titles, "en");
+
+    assert_eq!(issues.len(), 3);
+    assert!(issues
+        .iter()
+        .all(|(url, issue)| url != "https://example.com/contact"
+            && issue.code == "WCAGAAA.Principle2.Guideline2_4.F25"
+            && issue.message.contains('3')));
+
+    assert!(duplicate_title_issues(titles[..2].iter().copied(), "en").is_empty());
+}
+
+#[test]
+/// the duplicate title warnings of the crawled pages
+fn _audit_duplicate_titles_issues() {
+    use accessibility_rs::engine::rules::utils::title::{
+        duplicate_title_issues, DUPLICATE_TITLE_PAGES,
+    };
+
+    let urls = ["/a", "/b", "/c", "/d"];
+    let shared = urls
+        .iter()
+        .take(DUPLICATE_TITLE_PAGES)
+        .map(|url| (*url, "Products"));
+    let empty = urls.iter().map(|url| (*url, ""));
+    let issues = duplicate_title_issues(shared.chain(empty), "en");
+    let mut pages = issues
+        .iter()
+        .map(|(url, _)| url.as_str())
+        .collect::<Vec<_>>();
+
+    pages.sort();
+
+    assert_eq!(pages, urls[..DUPLICATE_TITLE_PAGES]);
+    assert!(issues.iter().all(|(_, issue)| issue.issue_type == "warning"
+        && issue.context == "title"
+        && issue.selectors == ["title"]
+        && issue
+            .message
+            .starts_with("The title of this document is shared by 3 pages")));
+}
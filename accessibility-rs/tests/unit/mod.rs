@@ -1,11 +1,21 @@
+pub mod abbr;
 pub mod anchor;
+pub mod animation;
 pub mod applet;
 pub mod area;
+pub mod button;
 pub mod contrast;
 pub mod fieldset;
 pub mod heading;
 pub mod html;
 pub mod img;
 pub mod input;
+pub mod keyboard;
 pub mod label;
+pub mod media;
 pub mod meta;
+pub mod presentation;
+pub mod readability;
+pub mod sensory;
+pub mod svg;
+pub mod target;
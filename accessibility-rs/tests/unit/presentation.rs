@@ -0,0 +1,94 @@
+//! Test for obsolete presentational markup.
+
+use accessibility_rs::AuditConfig;
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// obsolete presentational elements
+fn _audit_presentational_elements() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Legacy page</title></head>
+       <body>
+            <font color="red">Sale</font>
+            <font size="2">Ends soon</font>
+            <center>Welcome</center>
+            <big>Big news</big>
+            <tt>npm install</tt>
+            <p><em>Modern</em> markup</p>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H49")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 4);
+    assert!(issues
+        .iter()
+        .any(|x| x.message.starts_with("The font element") && x.selectors.len() == 2));
+    assert!(issues
+        .iter()
+        .any(|x| x.message.contains("text-align: center")));
+    assert!(issues.iter().any(|x| x.message.contains("font-size")));
+    assert!(issues.iter().any(|x| x.message.contains("monospace")));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// obsolete presentational attributes
+fn _audit_presentational_attributes() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Legacy page</title></head>
+       <body bgcolor="white" text="black">
+            <p align="center">Welcome</p>
+            <div align="right" style="color: red">Menu</div>
+            <table cellpadding="2"><tr><th>Name</th></tr><tr><td bgcolor="gray">Ada</td></tr></table>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H49")
+        .collect::<Vec<_>>();
+
+    let count = |start: &str| {
+        issues
+            .iter()
+            .find(|x| x.message.starts_with(start))
+            .map(|x| x.selectors.len())
+    };
+
+    assert_eq!(issues.len(), 4);
+    assert_eq!(count("Align attributes"), Some(2));
+    assert_eq!(count("Bgcolor attributes"), Some(2));
+    assert_eq!(count("The text, link"), Some(1));
+    assert_eq!(count("Cellpadding attributes"), Some(1));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// tables used for the layout of the page
+fn _audit_layout_tables() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Legacy page</title></head>
+       <body>
+            <table><tr><td><h1>Site</h1></td><td><nav><a href="/">Home</a></nav></td></tr></table>
+            <table role="presentation"><tr><td><h2>Aside</h2></td></tr></table>
+            <table><caption>Scores</caption><tr><td><table><tr><td>1</td></tr></table></td></tr></table>
+            <table><tr><td>Plain</td></tr></table>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.H49")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].message.contains("layout of the page"));
+    assert_eq!(issues[0].selectors.len(), 1);
+}
@@ -0,0 +1,117 @@
+//! Test for the readability of the content.
+
+use accessibility_rs::{AuditConfig, AuditOptions};
+
+/// a paragraph that needs a university reading level
+const COMPLEX_PARAGRAPH: &str = "Notwithstanding the aforementioned considerations, the administrative organization responsible for implementation anticipates substantial modifications regarding eligibility determinations, particularly concerning individuals experiencing considerable socioeconomic disadvantages, intergenerational unemployment, or complicated institutional relationships with the corresponding governmental authorities.";
+
+/// a paragraph that needs a primary school reading level
+const SIMPLE_PARAGRAPH: &str = "We will help you. You can ask us for a form. Fill in the form and send it back to us. We will read it and call you in a few days. Then we can talk about what you need and how we can help.";
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// paragraphs above the configured grade level
+fn _audit_readability_paragraphs() {
+    let html = format!(
+        r###"<html lang="en">
+       <head><title>Benefits</title></head>
+       <body><main><p>{COMPLEX_PARAGRAPH}</p><p>{SIMPLE_PARAGRAPH}</p></main></body>
+    </html>"###
+    );
+
+    // the check is optional
+    for (readability_grade, count) in [(None, 0), (Some(9.0), 1), (Some(60.0), 0)] {
+        let audit = accessibility_rs::audit(&AuditConfig {
+            html: &html,
+            options: AuditOptions {
+                readability_grade,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let issues = audit
+            .iter()
+            .filter(|x| x.code == "WCAGAAA.Principle3.Guideline3_1.G153")
+            .collect::<Vec<_>>();
+
+        assert_eq!(issues.len(), count, "{readability_grade:?}");
+
+        for issue in issues {
+            assert_eq!(issue.selectors.len(), 1);
+            assert!(issue.message.contains("grade level of 9"));
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// the main content above the configured grade level
+fn _audit_readability_page() {
+    let html = format!(
+        r###"<html lang="en">
+       <head><title>Benefits</title></head>
+       <body><header><p>{SIMPLE_PARAGRAPH}</p></header><main><p>{COMPLEX_PARAGRAPH}</p><p>{COMPLEX_PARAGRAPH}</p><p>{COMPLEX_PARAGRAPH}</p></main></body>
+    </html>"###
+    );
+    let audit = accessibility_rs::audit(&AuditConfig {
+        html: &html,
+        options: AuditOptions {
+            readability_grade: Some(9.0),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle3.Guideline3_1.G153")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 2);
+    assert!(issues
+        .iter()
+        .any(|x| x.message.starts_with("The main content") && x.selectors.len() == 1));
+    assert!(issues.iter().any(|x| x.selectors.len() == 3));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// navigation, headers, footers and unsupported languages are not graded
+fn _audit_readability_excluded() {
+    let html = format!(
+        r###"<html lang="en">
+       <head><title>Benefits</title></head>
+       <body><nav><p>{COMPLEX_PARAGRAPH}</p></nav><footer><p>{COMPLEX_PARAGRAPH}</p></footer><p lang="fr">{COMPLEX_PARAGRAPH}</p><p>{SIMPLE_PARAGRAPH}</p></body>
+    </html>"###
+    );
+    let audit = accessibility_rs::audit(&AuditConfig {
+        html: &html,
+        options: AuditOptions {
+            readability_grade: Some(9.0),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    assert!(!audit
+        .iter()
+        .any(|x| x.code == "WCAGAAA.Principle3.Guideline3_1.G153"));
+}
+
+#[test]
+/// the grade level of the text
+fn _readability_grade() {
+    use accessibility_rs::engine::rules::utils::readability::{syllables, Readability};
+
+    assert_eq!(syllables("cat"), 1);
+    assert_eq!(syllables("table"), 2);
+    assert_eq!(syllables("make"), 1);
+    assert_eq!(syllables("organization"), 5);
+
+    let simple = Readability::new(SIMPLE_PARAGRAPH);
+    let complex = Readability::new(COMPLEX_PARAGRAPH);
+
+    assert_eq!(simple.sentences, 5);
+    assert_eq!(complex.sentences, 1);
+    assert!(simple.grade() < 6.0);
+    assert!(complex.grade() > 16.0);
+}
@@ -0,0 +1,75 @@
+//! Test for instructions relying on sensory characteristics.
+
+use accessibility_rs::AuditConfig;
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// instructions that identify content by shape, colour or location
+fn _audit_sensory_characteristics() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Checkout</title></head>
+       <body>
+          <p>To continue, click the green button on the right.</p>
+          <p>Fields in red are required.</p>
+          <p>Copyright 2024. Select <strong>Pay now</strong> to finish.</p>
+          <script>var hint = "click the red button";</script>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.G96")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].selectors.len(), 2);
+    assert!(issues[0].message.contains("click the green"));
+    assert!(issues[0].message.contains("on the right"));
+    assert!(!issues[0].message.contains("click the red"));
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// the phrases of the language of the content
+fn _audit_sensory_characteristics_locale() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="fr">
+       <head><title>Paiement</title></head>
+       <body><p>Cliquez sur le bouton vert à droite pour continuer.</p></body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.G96")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].message.contains("à droite"));
+
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="ja">
+       <head><title>お支払い</title></head>
+       <body><p>続けるには右下の緑のボタンを押してください。</p></body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_3.G96")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+}
+
+#[test]
+/// phrases match whole words
+fn _sensory_phrases() {
+    use accessibility_rs::engine::rules::utils::sensory::contains_phrase;
+
+    assert!(contains_phrase("the menu on the right.", "on the right"));
+    assert!(!contains_phrase(
+        "the menu on the rightmost tab",
+        "on the right"
+    ));
+    assert!(contains_phrase("右下の緑のボタン", "右下の"));
+}
@@ -0,0 +1,107 @@
+//! Test for svg images.
+
+use accessibility_rs::AuditConfig;
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// svg images without a name
+fn _audit_svg_image_name() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Icons</title></head>
+       <body>
+            <svg role="img"><path d="M0 0h10v10z"/></svg>
+            <svg role="img" aria-labelledby="missing"><path d="M0 0h10v10z"/></svg>
+            <svg role="img"><title>Sales chart</title><desc>Sales rose in May</desc></svg>
+            <svg role="img" aria-label="Logo"><path d="M0 0h10v10z"/></svg>
+            <span id="caption">Map of the area</span>
+            <svg role="img" aria-labelledby="caption"><path d="M0 0h10v10z"/></svg>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_1.ARIA6,ARIA10")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0]
+        .message
+        .starts_with("Svg element with role=\"img\""));
+    assert_eq!(issues[0].selectors.len(), 2);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// links and buttons with only an svg as content
+fn _audit_svg_control_name() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Icons</title></head>
+       <body>
+            <a href="/"><svg aria-hidden="true"><path d="M0 0h10v10z"/></svg></a>
+            <button><svg><path d="M0 0h10v10z"/></svg></button>
+            <a href="/cart"><svg aria-hidden="true"><path d="M0 0h10v10z"/></svg> Cart</a>
+            <button><svg><title>Search</title><path d="M0 0h10v10z"/></svg></button>
+            <button aria-label="Close"><svg><path d="M0 0h10v10z"/></svg></button>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_1.ARIA6,ARIA10")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0]
+        .message
+        .contains("only content of a link or button"));
+    assert_eq!(issues[0].selectors.len(), 2);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// decorative svg elements exposed to assistive technology
+fn _audit_svg_decorative() {
+    let audit = accessibility_rs::audit(&AuditConfig::basic(
+        r###"<html lang="en">
+       <head><title>Icons</title></head>
+       <body>
+            <svg><path d="M0 0h10v10z"/></svg>
+            <svg aria-hidden="true"><path d="M0 0h10v10z"/></svg>
+            <svg role="presentation"><path d="M0 0h10v10z"/></svg>
+            <div aria-hidden="true"><svg><path d="M0 0h10v10z"/></svg></div>
+       </body>
+    </html>"###,
+    ));
+    let issues = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_1.ARIA6,ARIA10")
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].message.contains("decorative"));
+    assert_eq!(issues[0].selectors.len(), 1);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// svg files follow the alt rules of other images
+fn _audit_svg_img_alt() {
+    let html = r###"<html lang="en">
+       <head><title>Icons</title></head>
+       <body><img src="logo.svg"><img src="icon.svg" alt=""><object data="chart.svg" type="image/svg+xml">Chart</object></body>
+    </html>"###;
+    let audit = accessibility_rs::audit(&AuditConfig::basic(html));
+    let missing_alt = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle1.Guideline1_1.H37")
+        .map(|x| x.selectors.len())
+        .sum::<usize>();
+
+    assert_eq!(missing_alt, 1);
+    // svg objects are images and not media
+    assert!(!audit
+        .iter()
+        .any(|x| x.code.starts_with("WCAGAAA.Principle1.Guideline1_2")));
+}
@@ -0,0 +1,80 @@
+//! Test for pointer target sizes.
+
+use accessibility_rs::AuditConfig;
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// small targets placed next to each other
+fn _audit_target_size_minimum() {
+    let html = r###"<html lang="en">
+    <head>
+        <title>Toolbar</title>
+        <style>
+            .icon { width: 16px; height: 16px; }
+            .large { width: 48px; height: 48px; }
+        </style>
+    </head>
+    <body>
+        <div>
+            <button class="icon" aria-label="Bold"></button>
+            <button class="icon" aria-label="Italic"></button>
+        </div>
+        <button class="large">Save</button>
+        <p>Read the <a href="/terms">terms of service</a> before you continue.</p>
+    </body>
+</html>"###;
+    let audit = accessibility_rs::audit(&AuditConfig {
+        html,
+        bounding_box: true,
+        ..Default::default()
+    });
+    let targets = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle2.Guideline2_5.C42");
+    let minimum = targets
+        .clone()
+        .filter(|x| x.message.contains("24 by 24"))
+        .count();
+    let enhanced = targets.filter(|x| x.message.contains("44 by 44")).count();
+
+    // the icons fail the minimum and the enhanced target size
+    assert_eq!(minimum, 2);
+    assert_eq!(enhanced, 2);
+}
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+/// small targets with enough spacing between them
+fn _audit_target_size_spacing() {
+    let html = r###"<html lang="en">
+    <head>
+        <title>Toolbar</title>
+        <style>
+            .icon { width: 16px; height: 16px; margin: 8px; }
+        </style>
+    </head>
+    <body>
+        <div>
+            <button class="icon" aria-label="Bold"></button>
+            <button class="icon" aria-label="Italic"></button>
+        </div>
+    </body>
+</html>"###;
+    let audit = accessibility_rs::audit(&AuditConfig {
+        html,
+        bounding_box: true,
+        ..Default::default()
+    });
+    let targets = audit
+        .iter()
+        .filter(|x| x.code == "WCAGAAA.Principle2.Guideline2_5.C42");
+    let minimum = targets
+        .clone()
+        .filter(|x| x.message.contains("24 by 24"))
+        .count();
+    let enhanced = targets.filter(|x| x.message.contains("44 by 44")).count();
+
+    // only the enhanced target size fails
+    assert_eq!(minimum, 0);
+    assert_eq!(enhanced, 2);
+}
@@ -103,9 +103,8 @@ impl<'a> Element for ElementRef<'a> {
         use self::NonTSPseudoClass::*;
 
         match *pseudo_class {
-            Active | Focus | Hover | Enabled | Disabled | Checked | Indeterminate | Visited => {
-                false
-            }
+            Active | Focus | FocusVisible | FocusWithin | Hover | Enabled | Disabled | Checked
+            | Indeterminate | Visited => false,
             AnyLink | Link => {
                 self.value().name.ns == ns!(html)
                     && matches!(
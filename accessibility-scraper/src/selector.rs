@@ -67,6 +67,19 @@ pub struct Parser;
 impl<'i> parser::Parser<'i> for Parser {
     type Impl = Simple;
     type Error = SelectorParseErrorKind<'i>;
+
+    fn parse_non_ts_pseudo_class(
+        &self,
+        location: cssparser::SourceLocation,
+        name: cssparser::CowRcStr<'i>,
+    ) -> Result<NonTSPseudoClass, cssparser::ParseError<'i, Self::Error>> {
+        match NonTSPseudoClass::from_name(&name) {
+            Some(pseudo_class) => Ok(pseudo_class),
+            _ => Err(location.new_custom_error(
+                SelectorParseErrorKind::UnsupportedPseudoClassOrElement(name),
+            )),
+        }
+    }
 }
 
 /// A simple implementation of `SelectorImpl` with no pseudo-classes or pseudo-elements.
@@ -121,14 +134,14 @@ impl cssparser::ToCss for CssString {
 pub struct CssLocalName(pub LocalName);
 
 impl std::fmt::Display for CssString {
-    fn fmt(&self, _: &mut core::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        Ok(())
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.write_str(&self.0)
     }
 }
 
 impl std::fmt::Display for CssLocalName {
-    fn fmt(&self, _: &mut core::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        Ok(())
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.write_str(&self.0)
     }
 }
 
@@ -143,7 +156,7 @@ impl cssparser::ToCss for CssLocalName {
     where
         W: fmt::Write,
     {
-        dest.write_str(&self.0)
+        cssparser::serialize_identifier(&self.0, dest)
     }
 }
 
@@ -160,6 +173,10 @@ pub enum NonTSPseudoClass {
     Active,
     /// a focused element
     Focus,
+    /// a focused element that should show a focus indicator
+    FocusVisible,
+    /// a element that is focused or contains the focused element
+    FocusWithin,
     /// a element that is hovered
     Hover,
     /// a element that has enabled checked
@@ -172,6 +189,35 @@ pub enum NonTSPseudoClass {
     Indeterminate,
 }
 
+impl NonTSPseudoClass {
+    /// the pseudo-class for the name without the colon
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "any-link" => Some(NonTSPseudoClass::AnyLink),
+            "link" => Some(NonTSPseudoClass::Link),
+            "visited" => Some(NonTSPseudoClass::Visited),
+            "active" => Some(NonTSPseudoClass::Active),
+            "focus" => Some(NonTSPseudoClass::Focus),
+            "focus-visible" => Some(NonTSPseudoClass::FocusVisible),
+            "focus-within" => Some(NonTSPseudoClass::FocusWithin),
+            "hover" => Some(NonTSPseudoClass::Hover),
+            "enabled" => Some(NonTSPseudoClass::Enabled),
+            "disabled" => Some(NonTSPseudoClass::Disabled),
+            "checked" => Some(NonTSPseudoClass::Checked),
+            "indeterminate" => Some(NonTSPseudoClass::Indeterminate),
+            _ => None,
+        }
+    }
+
+    /// the pseudo-class matches the element in the focus state
+    pub fn is_focus(&self) -> bool {
+        matches!(
+            self,
+            NonTSPseudoClass::Focus | NonTSPseudoClass::FocusVisible
+        )
+    }
+}
+
 impl parser::NonTSPseudoClass for NonTSPseudoClass {
     type Impl = Simple;
     fn is_active_or_hover(&self) -> bool {
@@ -196,6 +242,8 @@ impl cssparser::ToCss for NonTSPseudoClass {
             NonTSPseudoClass::Visited => ":visited",
             NonTSPseudoClass::Active => ":active",
             NonTSPseudoClass::Focus => ":focus",
+            NonTSPseudoClass::FocusVisible => ":focus-visible",
+            NonTSPseudoClass::FocusWithin => ":focus-within",
             NonTSPseudoClass::Hover => ":hover",
             NonTSPseudoClass::Enabled => ":enabled",
             NonTSPseudoClass::Disabled => ":disabled",
@@ -244,6 +292,12 @@ mod tests {
         let _sel: Selector = (*s).try_into().unwrap();
     }
 
+    #[test]
+    fn pseudo_class_selector_conversions() {
+        let s = "a:focus, button:focus-visible, form:focus-within, a:hover";
+        let _sel: Selector = s.try_into().unwrap();
+    }
+
     #[test]
     #[should_panic]
     fn invalid_selector_conversions() {
@@ -20,3 +20,33 @@ fn tag_with_newline() {
         Some("https://github.com/causal-agent/accessibility-scraper")
     );
 }
+
+#[test]
+fn svg_title_and_desc() {
+    let document = Html::parse_document(
+        r#"<html><head><title>Report</title></head><body>
+        <svg role="img" aria-labelledby="chart-title"><title id="chart-title">Sales chart</title><desc>Sales rose in May</desc><path d="M0 0"/></svg>
+        </body></html>"#,
+    );
+
+    let title = document
+        .select(&Selector::parse("svg > title").unwrap())
+        .next()
+        .unwrap();
+    assert_eq!(title.value().name.ns, ns!(svg));
+    assert_eq!(title.text().collect::<String>(), "Sales chart");
+
+    let desc = document
+        .select(&Selector::parse("svg > desc").unwrap())
+        .next()
+        .unwrap();
+    assert_eq!(desc.value().name.ns, ns!(svg));
+    assert_eq!(desc.text().collect::<String>(), "Sales rose in May");
+
+    // the svg title is not the title of the document
+    let head_title = document
+        .select(&Selector::parse("head > title").unwrap())
+        .next()
+        .unwrap();
+    assert_eq!(head_title.value().name.ns, ns!(html));
+}
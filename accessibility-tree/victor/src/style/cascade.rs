@@ -1,10 +1,11 @@
 use crate::dom;
 use crate::style::declaration_block::DeclarationBlock;
 use crate::style::properties::{ComputedValues, Phase};
-use crate::style::rules::{CssRule, RulesParser};
+use crate::style::rules::{CssRule, MotionPreference, RulesParser};
 use accessibility_scraper::{ElementRef, Html};
 use cssparser::{Parser, ParserInput, RuleListParser};
 use smallvec::SmallVec;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 pub struct StyleSetBuilder(StyleSet);
@@ -12,6 +13,104 @@ pub struct StyleSetBuilder(StyleSet);
 #[derive(Clone, Debug, Default)]
 pub struct StyleSet {
     pub rules: Vec<(crate::style::selectors::Selector, Arc<DeclarationBlock>)>,
+    /// rules that only apply in the focus state with the specificity of the source selector
+    pub focus_rules: Vec<(
+        crate::style::selectors::Selector,
+        u32,
+        Arc<DeclarationBlock>,
+    )>,
+    /// rules that only apply when the user prefers reduced motion with the specificity of the selector
+    pub reduced_motion_rules: Vec<(
+        crate::style::selectors::Selector,
+        u32,
+        Arc<DeclarationBlock>,
+    )>,
+    /// rules that only apply when the user has no motion preference with the specificity of the selector
+    pub motion_rules: Vec<(
+        crate::style::selectors::Selector,
+        u32,
+        Arc<DeclarationBlock>,
+    )>,
+    /// the positions of the rules bucketed by the rightmost compound selector
+    pub index: RuleIndex,
+}
+
+/// the state of the element and the preferences of the user the rules are matched in
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MatchState {
+    /// the element has focus
+    pub focus: bool,
+    /// the user prefers reduced motion
+    pub reduced_motion: bool,
+}
+
+/// the rules bucketed by the id, class or tag of the rightmost compound selector
+#[derive(Clone, Debug, Default)]
+pub struct RuleIndex {
+    ids: HashMap<String, Vec<usize>>,
+    classes: HashMap<String, Vec<usize>>,
+    tags: HashMap<String, Vec<usize>>,
+    universal: Vec<usize>,
+}
+
+impl RuleIndex {
+    fn new(rules: &[(crate::style::selectors::Selector, Arc<DeclarationBlock>)]) -> Self {
+        use selectors::parser::Component;
+
+        let mut index = RuleIndex::default();
+
+        for (position, (selector, _)) in rules.iter().enumerate() {
+            let mut id = None;
+            let mut class = None;
+            let mut tag = None;
+
+            for component in selector.iter() {
+                match component {
+                    Component::ID(name) => id = Some(name.0.to_string()),
+                    Component::Class(name) => class = Some(name.0.to_string()),
+                    Component::LocalName(name) => tag = Some(name.lower_name.0.to_string()),
+                    _ => (),
+                }
+            }
+
+            let bucket = if let Some(id) = id {
+                index.ids.entry(id).or_default()
+            } else if let Some(class) = class {
+                index.classes.entry(class).or_default()
+            } else if let Some(tag) = tag {
+                index.tags.entry(tag).or_default()
+            } else {
+                &mut index.universal
+            };
+
+            bucket.push(position)
+        }
+
+        index
+    }
+
+    /// the positions of the rules that can match the element in order
+    fn candidates(&self, node: &ElementRef) -> Vec<usize> {
+        let element = node.value();
+        let mut candidates = self.universal.clone();
+
+        if let Some(rules) = element.id().and_then(|id| self.ids.get(id)) {
+            candidates.extend(rules);
+        }
+        for class in element.classes() {
+            if let Some(rules) = self.classes.get(class) {
+                candidates.extend(rules);
+            }
+        }
+        // the tags are bucketed by the lower case name to match foreign elements
+        if let Some(rules) = self.tags.get(&element.name().to_ascii_lowercase()) {
+            candidates.extend(rules);
+        }
+
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+    }
 }
 
 lazy_static::lazy_static! {
@@ -24,7 +123,13 @@ lazy_static::lazy_static! {
 
 impl StyleSetBuilder {
     pub fn new() -> Self {
-        StyleSetBuilder(StyleSet { rules: Vec::new() })
+        StyleSetBuilder(StyleSet {
+            rules: Vec::new(),
+            focus_rules: Vec::new(),
+            reduced_motion_rules: Vec::new(),
+            motion_rules: Vec::new(),
+            index: RuleIndex::default(),
+        })
     }
 
     pub fn add_stylesheet(&mut self, css: &str) {
@@ -32,13 +137,52 @@ impl StyleSetBuilder {
         let mut parser = Parser::new(&mut input);
         for result in RuleListParser::new_for_stylesheet(&mut parser, RulesParser) {
             match result {
-                Ok(CssRule::StyleRule { selectors, block }) => {
+                Ok(rule) => self.add_rule(rule, None),
+                Err(_) => {
+                    // FIXME: error reporting
+                }
+            }
+        }
+    }
+
+    /// add the rule that applies under the motion preference of the enclosing media rules
+    fn add_rule(&mut self, rule: CssRule, preference: Option<MotionPreference>) {
+        match rule {
+            CssRule::StyleRule {
+                selectors,
+                block,
+                focus_selectors,
+            } => match preference {
+                None => {
                     for selector in selectors.0 {
                         self.0.rules.push((selector, block.clone()));
                     }
+                    for (selector, specificity) in focus_selectors {
+                        self.0
+                            .focus_rules
+                            .push((selector, specificity, block.clone()));
+                    }
                 }
-                Err(_) => {
-                    // FIXME: error reporting
+                Some(preference) => {
+                    let rules = match preference {
+                        MotionPreference::Reduce => &mut self.0.reduced_motion_rules,
+                        MotionPreference::NoPreference => &mut self.0.motion_rules,
+                    };
+                    for selector in selectors.0 {
+                        let specificity = selector.specificity();
+                        rules.push((selector, specificity, block.clone()));
+                    }
+                }
+            },
+            CssRule::MediaRule {
+                preference: nested,
+                rules,
+            } => {
+                // nested media rules on opposite preferences never apply
+                if preference.is_none_or(|preference| preference == nested) {
+                    for rule in rules {
+                        self.add_rule(rule, Some(nested));
+                    }
                 }
             }
         }
@@ -49,6 +193,14 @@ impl StyleSetBuilder {
         self.0
             .rules
             .sort_by_key(|&(ref selector, _)| selector.specificity());
+        for rules in [
+            &mut self.0.focus_rules,
+            &mut self.0.reduced_motion_rules,
+            &mut self.0.motion_rules,
+        ] {
+            rules.sort_by_key(|&(_, specificity, _)| specificity);
+        }
+        self.0.index = RuleIndex::new(&self.0.rules);
         self.0
     }
 }
@@ -114,53 +266,106 @@ pub fn style_for_element(
 
 pub fn _style_for_element<'a>(
     author: &StyleSet,
-    _document: &accessibility_scraper::Html,
+    document: &accessibility_scraper::Html,
     node: &ElementRef<'a>,
     parent_style: Option<&ComputedValues>,
 ) -> Arc<ComputedValues> {
-    // use smallvec::SmallVec;
-    let style_attr_block;
-    let mut matching = crate::style::cascade::MatchingDeclarations {
-        ua: SmallVec::new(),
-        author: SmallVec::new(),
-    };
+    _style_for_element_state(author, document, node, parent_style, MatchState::default())
+}
 
-    let mut nth_index_cache = selectors::NthIndexCache::from(Default::default());
-    let mut match_context = selectors::matching::MatchingContext::new(
-        selectors::matching::MatchingMode::Normal,
-        None,
-        Some(&mut nth_index_cache),
-        selectors::matching::QuirksMode::NoQuirks,
-        // selectors::matching::NeedsSelectorFlags::No,
-        // selectors::matching::IgnoreNthChildForInvalidation::No,
-    );
+/// the declaration blocks of the style set matching the element in specificity order
+fn matching_blocks<'s>(
+    style_set: &'s StyleSet,
+    node: &ElementRef,
+    state: MatchState,
+    match_context: &mut selectors::matching::MatchingContext<
+        accessibility_scraper::selector::Simple,
+    >,
+) -> Vec<&'s DeclarationBlock> {
+    let mut blocks = Vec::new();
+
+    for position in style_set.index.candidates(node) {
+        let (selector, block) = &style_set.rules[position];
 
-    for &(ref selector, ref block) in &USER_AGENT_STYLESHEET.rules {
         if selectors::matching::matches_selector(
             selector,
             0,
             None,
             node,
-            &mut match_context,
+            match_context,
             &mut |_, _| {},
         ) {
-            matching.ua.push(block)
+            blocks.push((selector.specificity(), &**block))
         }
     }
 
-    // push author style sheet
-    for &(ref selector, ref block) in &author.rules {
+    let focus_rules = if state.focus {
+        &style_set.focus_rules[..]
+    } else {
+        &[]
+    };
+    let motion_rules = if state.reduced_motion {
+        &style_set.reduced_motion_rules
+    } else {
+        &style_set.motion_rules
+    };
+    let unconditional = blocks.len();
+
+    for (selector, specificity, block) in focus_rules.iter().chain(motion_rules) {
         if selectors::matching::matches_selector(
             selector,
             0,
             None,
             node,
-            &mut match_context,
+            match_context,
             &mut |_, _| {},
         ) {
-            matching.author.push(block)
+            blocks.push((*specificity, &**block))
         }
     }
+    if blocks.len() > unconditional {
+        // Sort stability keeps the conditional rules after the rules of equal specificity
+        blocks.sort_by_key(|&(specificity, _)| specificity);
+    }
+
+    blocks.into_iter().map(|(_, block)| block).collect()
+}
+
+pub fn _style_for_element_state<'a>(
+    author: &StyleSet,
+    _document: &accessibility_scraper::Html,
+    node: &ElementRef<'a>,
+    parent_style: Option<&ComputedValues>,
+    state: MatchState,
+) -> Arc<ComputedValues> {
+    // use smallvec::SmallVec;
+    let style_attr_block;
+    let mut matching = crate::style::cascade::MatchingDeclarations {
+        ua: SmallVec::new(),
+        author: SmallVec::new(),
+    };
+
+    let mut nth_index_cache = selectors::NthIndexCache::from(Default::default());
+    let mut match_context = selectors::matching::MatchingContext::new(
+        selectors::matching::MatchingMode::Normal,
+        None,
+        Some(&mut nth_index_cache),
+        selectors::matching::QuirksMode::NoQuirks,
+        // selectors::matching::NeedsSelectorFlags::No,
+        // selectors::matching::IgnoreNthChildForInvalidation::No,
+    );
+
+    matching.ua.extend(matching_blocks(
+        &USER_AGENT_STYLESHEET,
+        node,
+        state,
+        &mut match_context,
+    ));
+
+    // push author style sheet
+    matching
+        .author
+        .extend(matching_blocks(author, node, state, &mut match_context));
 
     if let ns!(html) | ns!(svg) | ns!(mathml) = node.value().name.ns {
         if let Some(style_attr) = node.value().attr(&local_name!("style")) {
@@ -176,6 +381,47 @@ pub fn _style_for_element<'a>(
     styles
 }
 
+/// the author rules or the style attribute matching the element declare an animation or transition
+///
+/// Only the rules declaring motion are matched which is cheaper than computing the style.
+pub fn declares_motion(author: &StyleSet, node: &ElementRef) -> bool {
+    let mut nth_index_cache = selectors::NthIndexCache::default();
+    let mut match_context = selectors::matching::MatchingContext::new(
+        selectors::matching::MatchingMode::Normal,
+        None,
+        Some(&mut nth_index_cache),
+        selectors::matching::QuirksMode::NoQuirks,
+    );
+    let mut matches = |selector: &crate::style::selectors::Selector| {
+        selectors::matching::matches_selector(
+            selector,
+            0,
+            None,
+            node,
+            &mut match_context,
+            &mut |_, _| {},
+        )
+    };
+
+    let declared = author.index.candidates(node).into_iter().any(|position| {
+        let (selector, block) = &author.rules[position];
+        block.declares_motion() && matches(selector)
+    }) || author
+        .motion_rules
+        .iter()
+        .any(|(selector, _, block)| block.declares_motion() && matches(selector));
+
+    declared
+        || node
+            .value()
+            .attr(&local_name!("style"))
+            .is_some_and(|style| {
+                let mut input = ParserInput::new(style);
+                let mut parser = Parser::new(&mut input);
+                DeclarationBlock::parse(&mut parser).declares_motion()
+            })
+}
+
 /// get the style for a node parsed with accessibility_scraper
 pub fn style_for_element_ref(
     node: &ElementRef,
@@ -194,3 +440,61 @@ pub fn style_for_element_ref(
     };
     _style_for_element(&style_set, &document, node, parent_styles.as_deref())
 }
+
+#[test]
+#[cfg(not(feature = "tokio"))]
+fn rule_index_matches_full_scan() {
+    let mut builder = StyleSetBuilder::new();
+    builder.add_stylesheet(
+        "* { color: red }
+        p { color: red }
+        P.lead { color: red }
+        #main .card > a:hover, .card a { color: red }
+        div#main { color: red }
+        .a.b, [role=button], svg foreignObject { color: red }
+        :not(.card) > span, ul li:nth-child(2) { color: red }
+        input[type=text]:focus-visible, section:is(.a, #b) { color: red }",
+    );
+    let style_set = builder.finish();
+    let document = Html::parse_document(
+        r#"<div id="main"><p class="lead">Intro</p><div class="card"><a href="/">Home</a>
+        <span class="a b">Badge</span></div><ul><li>One</li><li>Two</li></ul>
+        <section id="b"><input type="text"><div role="button">Go</div></section>
+        <svg><foreignObject><span>Label</span></foreignObject></svg></div>"#,
+    );
+    let mut nth_index_cache = selectors::NthIndexCache::default();
+    let mut match_context = selectors::matching::MatchingContext::new(
+        selectors::matching::MatchingMode::Normal,
+        None,
+        Some(&mut nth_index_cache),
+        selectors::matching::QuirksMode::NoQuirks,
+    );
+    let mut matched = 0;
+
+    for node in document.tree.nodes().filter_map(ElementRef::wrap) {
+        let mut matches = |position: &usize| {
+            selectors::matching::matches_selector(
+                &style_set.rules[*position].0,
+                0,
+                None,
+                &node,
+                &mut match_context,
+                &mut |_, _| {},
+            )
+        };
+        let scanned = (0..style_set.rules.len())
+            .filter(|position| matches(position))
+            .collect::<Vec<_>>();
+        let indexed = style_set
+            .index
+            .candidates(&node)
+            .into_iter()
+            .filter(|position| matches(position))
+            .collect::<Vec<_>>();
+
+        assert_eq!(scanned, indexed, "{}", node.value().name());
+        matched += scanned.len();
+    }
+
+    assert!(matched > 20);
+}
@@ -17,6 +17,8 @@ pub struct DeclarationBlock {
     important: smallbitvec::SmallBitVec,
     any_important: PerPhase<bool>,
     any_normal: PerPhase<bool>,
+    /// an animation or transition property is declared
+    declares_motion: bool,
 }
 
 impl DeclarationBlock {
@@ -56,6 +58,11 @@ impl DeclarationBlock {
         iter.parser.block
     }
 
+    /// an animation or transition property is declared in the block
+    pub fn declares_motion(&self) -> bool {
+        self.declares_motion
+    }
+
     pub fn cascade_normal(&self, phase: &mut impl Phase) {
         self.cascade(false, self.any_normal, phase)
     }
@@ -120,6 +127,9 @@ impl<'i> DeclarationParser<'i> for LonghandDeclarationParser {
                 };
                 any.early |= parsed.early;
                 any.late |= parsed.late;
+                let name = name.to_ascii_lowercase();
+                self.block.declares_motion |=
+                    name.starts_with("animation") || name.starts_with("transition");
                 Ok(())
             } else {
                 Err(parser.new_custom_error(PropertyParseErrorKind::UnknownUnit(name)))
@@ -12,6 +12,11 @@ properties! {
 
     inherited struct font {
         @early font_size { "font-size", FontSize, initial = Length { px: 16. } }
+        font_weight { "font-weight", FontWeight, initial = FontWeight::NORMAL }
+    }
+
+    inherited struct inherited_box {
+        visibility { "visibility", Visibility, initial = Visibility::Visible }
     }
 
     inherited struct color {
@@ -29,6 +34,13 @@ properties! {
         right { "right", LengthOrPercentageOrAuto, initial = LengthOrPercentageOrAuto::Auto }
         width { "width", LengthOrPercentageOrAuto, initial = LengthOrPercentageOrAuto::Auto }
         height { "height", LengthOrPercentageOrAuto, initial = LengthOrPercentageOrAuto::Auto }
+        overflow { "overflow", Overflow, initial = Overflow::Visible }
+    }
+
+    reset struct effects {
+        opacity { "opacity", Opacity, initial = Opacity(1.) }
+        clip { "clip", Clip, initial = Clip::Auto }
+        clip_path { "clip-path", ClipPath, initial = ClipPath::None }
     }
 
     reset struct margin {
@@ -49,7 +61,7 @@ properties! {
         border_top_color { "border-top-color", Color, initial = Color::CurrentColor }
         border_left_color { "border-left-color", Color, initial = Color::CurrentColor }
         border_bottom_color { "border-bottom-color", Color, initial = Color::CurrentColor }
-        border_right_color { "border-color-right", Color, initial = Color::CurrentColor }
+        border_right_color { "border-right-color", Color, initial = Color::CurrentColor }
 
         border_top_style { "border-top-style", LineStyle, initial = LineStyle::None }
         border_left_style { "border-left-style", LineStyle, initial = LineStyle::None }
@@ -62,8 +74,28 @@ properties! {
         border_right_width { "border-right-width", LineWidth, initial = LineWidth::MEDIUM }
     }
 
+    reset struct text {
+        text_decoration_line { "text-decoration-line", TextDecorationLine, initial = TextDecorationLine::NONE }
+    }
+
+    reset struct animation {
+        animation_name { "animation-name", AnimationName, initial = AnimationName::None }
+        animation_duration { "animation-duration", Time, initial = Time(0.) }
+        animation_iteration_count { "animation-iteration-count", IterationCount, initial = IterationCount::Count(1.) }
+        transition_property { "transition-property", TransitionProperty, initial = TransitionProperty::Motion }
+        transition_duration { "transition-duration", Time, initial = Time(0.) }
+    }
+
+    reset struct outline {
+        outline_color { "outline-color", Color, initial = Color::CurrentColor }
+        outline_style { "outline-style", OutlineStyle, initial = OutlineStyle::Style(LineStyle::None) }
+        outline_width { "outline-width", LineWidth, initial = LineWidth::MEDIUM }
+    }
+
     reset struct background {
         background_color { "background-color", Color, initial = Color::RGBA(RGBA::transparent()) }
+        background_image { "background-image", BackgroundImage, initial = BackgroundImage::None }
+        box_shadow { "box-shadow", BoxShadow, initial = BoxShadow::None }
     }
 
     @shorthands {
@@ -102,8 +134,55 @@ properties! {
             color: border_top_color,
             width: border_top_width,
         }
+        "border-left" => BorderSide {
+            style: border_left_style,
+            color: border_left_color,
+            width: border_left_width,
+        }
+        "border-bottom" => BorderSide {
+            style: border_bottom_style,
+            color: border_bottom_color,
+            width: border_bottom_width,
+        }
+        "border-right" => BorderSide {
+            style: border_right_style,
+            color: border_right_color,
+            width: border_right_width,
+        }
+        "border" => Border {
+            top_style: border_top_style,
+            left_style: border_left_style,
+            bottom_style: border_bottom_style,
+            right_style: border_right_style,
+            top_color: border_top_color,
+            left_color: border_left_color,
+            bottom_color: border_bottom_color,
+            right_color: border_right_color,
+            top_width: border_top_width,
+            left_width: border_left_width,
+            bottom_width: border_bottom_width,
+            right_width: border_right_width,
+        }
+        "text-decoration" => TextDecoration {
+            line: text_decoration_line,
+        }
+        "outline" => Outline {
+            style: outline_style,
+            color: outline_color,
+            width: outline_width,
+        }
+        "animation" => Animation {
+            name: animation_name,
+            duration: animation_duration,
+            iteration_count: animation_iteration_count,
+        }
+        "transition" => Transition {
+            property: transition_property,
+            duration: transition_duration,
+        }
         "background" => Background {
             color: background_color,
+            image: background_image,
         }
     }
 }
@@ -4,7 +4,7 @@ pub use self::definitions::{property_data_by_name, LonghandDeclaration};
 pub use self::definitions::{ComputedValuesForEarlyCascade, ComputedValuesForLateCascade};
 use crate::geom::{flow_relative, physical};
 use crate::style::errors::PropertyParseError;
-use crate::style::values::{self, CssWideKeyword, Direction, Display, WritingMode};
+use crate::style::values::{self, CssWideKeyword, Direction, Display, OutlineStyle, WritingMode};
 use crate::style::values::{CascadeContext, EarlyCascadeContext};
 use cssparser::{Color, RGBA};
 use std::sync::Arc;
@@ -26,6 +26,10 @@ impl ComputedValues {
         b.border_bottom_width.fixup(b.border_bottom_style);
         b.border_right_width.fixup(b.border_right_style);
 
+        if let OutlineStyle::Style(style) = self.outline.outline_style {
+            Arc::make_mut(&mut self.outline).outline_width.fixup(style);
+        }
+
         Display::fixup(self);
     }
 
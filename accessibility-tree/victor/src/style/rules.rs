@@ -1,9 +1,79 @@
 use crate::style::declaration_block::DeclarationBlock;
 use crate::style::errors::RuleParseErrorKind;
 use crate::style::selectors::{self, SelectorList};
-use cssparser::{AtRuleParser, ParseError, Parser, QualifiedRuleParser, SourceLocation};
+use cssparser::{
+    AtRuleParser, AtRuleType, BasicParseErrorKind, CowRcStr, ParseError, Parser,
+    QualifiedRuleParser, RuleListParser, SourceLocation, Token,
+};
 use std::sync::Arc;
 
+/// the reduced motion preference of the user a media rule applies to
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MotionPreference {
+    /// `prefers-reduced-motion: reduce`
+    Reduce,
+    /// `prefers-reduced-motion: no-preference`
+    NoPreference,
+}
+
+impl MotionPreference {
+    /// the opposite preference
+    fn negate(self) -> Self {
+        match self {
+            MotionPreference::Reduce => MotionPreference::NoPreference,
+            MotionPreference::NoPreference => MotionPreference::Reduce,
+        }
+    }
+
+    /// parse a media query on the reduced motion preference
+    ///
+    /// Other media features are ignored and query lists are not kept.
+    fn parse<'i, 't>(
+        parser: &mut Parser<'i, 't>,
+    ) -> Result<Self, ParseError<'i, RuleParseErrorKind<'i>>> {
+        let mut negated = false;
+        let mut preference = None;
+
+        while !parser.is_exhausted() {
+            match parser.next()?.clone() {
+                Token::Ident(ident) if ident.eq_ignore_ascii_case("not") => negated = true,
+                Token::ParenthesisBlock => {
+                    let feature = parser.parse_nested_block(|parser| {
+                        if parser
+                            .r#try(|parser| parser.expect_ident_matching("prefers-reduced-motion"))
+                            .is_err()
+                        {
+                            while parser.next().is_ok() {}
+                            return Ok(None);
+                        }
+                        if parser.r#try(|parser| parser.expect_colon()).is_err() {
+                            return Ok(Some(MotionPreference::Reduce));
+                        }
+                        let value = parser.expect_ident()?.clone();
+
+                        if value.eq_ignore_ascii_case("reduce") {
+                            Ok(Some(MotionPreference::Reduce))
+                        } else if value.eq_ignore_ascii_case("no-preference") {
+                            Ok(Some(MotionPreference::NoPreference))
+                        } else {
+                            Err(parser.new_unexpected_token_error(Token::Ident(value)))
+                        }
+                    })?;
+                    preference = preference.or(feature);
+                }
+                Token::Comma => return Err(parser.new_error_for_next_token()),
+                _ => (),
+            }
+        }
+
+        match preference {
+            Some(preference) if negated => Ok(preference.negate()),
+            Some(preference) => Ok(preference),
+            _ => Err(parser.new_error_for_next_token()),
+        }
+    }
+}
+
 pub enum CssRule {
     StyleRule {
         selectors: SelectorList,
@@ -14,13 +84,21 @@ pub enum CssRule {
         //
         // Use `Arc` to enable having multiple references to the `Vec` without cloning it.
         block: Arc<DeclarationBlock>,
+
+        // The selectors that match in the focus state with the specificity of the source selector
+        focus_selectors: Vec<(selectors::Selector, u32)>,
+    },
+    /// the rules of a media rule on the reduced motion preference
+    MediaRule {
+        preference: MotionPreference,
+        rules: Vec<CssRule>,
     },
 }
 
 pub struct RulesParser;
 
 impl<'i> QualifiedRuleParser<'i> for RulesParser {
-    type Prelude = SelectorList;
+    type Prelude = (SelectorList, Vec<(selectors::Selector, u32)>);
     type QualifiedRule = CssRule;
     type Error = RuleParseErrorKind<'i>;
 
@@ -28,7 +106,9 @@ impl<'i> QualifiedRuleParser<'i> for RulesParser {
         &mut self,
         parser: &mut Parser<'i, 't>,
     ) -> Result<Self::Prelude, ParseError<'i, Self::Error>> {
-        SelectorList::parse(&selectors::Parser, parser)
+        let list = SelectorList::parse(&selectors::Parser, parser)?;
+        let focus = selectors::focus_selectors(&list);
+        Ok((list, focus))
     }
 
     fn parse_block<'t>(
@@ -38,15 +118,43 @@ impl<'i> QualifiedRuleParser<'i> for RulesParser {
         parser: &mut Parser<'i, 't>,
     ) -> Result<Self::QualifiedRule, ParseError<'i, Self::Error>> {
         Ok(CssRule::StyleRule {
-            selectors: prelude,
+            selectors: prelude.0,
             block: Arc::new(DeclarationBlock::parse(parser)),
+            focus_selectors: prelude.1,
         })
     }
 }
 
 impl<'i> AtRuleParser<'i> for RulesParser {
     type PreludeNoBlock = ();
-    type PreludeBlock = ();
+    type PreludeBlock = MotionPreference;
     type AtRule = CssRule;
     type Error = RuleParseErrorKind<'i>;
+
+    fn parse_prelude<'t>(
+        &mut self,
+        name: CowRcStr<'i>,
+        parser: &mut Parser<'i, 't>,
+    ) -> Result<AtRuleType<Self::PreludeNoBlock, Self::PreludeBlock>, ParseError<'i, Self::Error>>
+    {
+        if name.eq_ignore_ascii_case("media") {
+            MotionPreference::parse(parser).map(AtRuleType::WithBlock)
+        } else {
+            Err(parser.new_error(BasicParseErrorKind::AtRuleInvalid(name)))
+        }
+    }
+
+    fn parse_block<'t>(
+        &mut self,
+        preference: Self::PreludeBlock,
+        _location: SourceLocation,
+        parser: &mut Parser<'i, 't>,
+    ) -> Result<Self::AtRule, ParseError<'i, Self::Error>> {
+        Ok(CssRule::MediaRule {
+            preference,
+            rules: RuleListParser::new_for_nested_rule(parser, RulesParser)
+                .filter_map(Result::ok)
+                .collect(),
+        })
+    }
 }
@@ -1,11 +1,12 @@
 use crate::dom::{Document, Node, NodeId};
 use crate::style::errors::RuleParseErrorKind;
 use accessibility_scraper::selector::CssLocalName;
-use accessibility_scraper::selector::Simple;
+use accessibility_scraper::selector::{NonTSPseudoClass, Simple};
 use fast_html5ever::{LocalName, Namespace};
 use selectors::attr::{AttrSelectorOperation, CaseSensitivity, NamespaceConstraint};
 use selectors::context::{MatchingContext, MatchingMode, QuirksMode};
 use selectors::matching::{matches_selector, ElementSelectorFlags};
+use selectors::parser::{Component, SelectorParseErrorKind};
 
 pub type SelectorList = selectors::SelectorList<Simple>;
 pub type Selector = selectors::parser::Selector<Simple>;
@@ -30,6 +31,98 @@ pub struct Parser;
 impl<'i> selectors::parser::Parser<'i> for Parser {
     type Impl = Simple;
     type Error = RuleParseErrorKind<'i>;
+
+    fn parse_non_ts_pseudo_class(
+        &self,
+        location: cssparser::SourceLocation,
+        name: cssparser::CowRcStr<'i>,
+    ) -> Result<NonTSPseudoClass, cssparser::ParseError<'i, Self::Error>> {
+        match NonTSPseudoClass::from_name(&name) {
+            Some(pseudo_class) => Ok(pseudo_class),
+            _ => Err(location.new_custom_error(RuleParseErrorKind::Selector(
+                SelectorParseErrorKind::UnsupportedPseudoClassOrElement(name),
+            ))),
+        }
+    }
+}
+
+/// the selector only matches elements in the focus state
+pub fn has_focus_state(selector: &Selector) -> bool {
+    let mut iter = selector.iter();
+    loop {
+        for component in &mut iter {
+            if let Component::NonTSPseudoClass(pseudo_class) = component {
+                if pseudo_class.is_focus() {
+                    return true;
+                }
+            }
+        }
+        if iter.next_sequence().is_none() {
+            return false;
+        }
+    }
+}
+
+/// write the source of the selector with the focus pseudo-classes of its compound selectors removed
+fn strip_focus_state<W: std::fmt::Write>(selector: &Selector, dest: &mut W) -> std::fmt::Result {
+    use cssparser::ToCss;
+
+    let mut combinators = selector
+        .iter_raw_match_order()
+        .rev()
+        .filter_map(|component| component.as_combinator());
+    let compounds = selector
+        .iter_raw_match_order()
+        .as_slice()
+        .split(|component| component.is_combinator())
+        .rev();
+
+    for compound in compounds {
+        // the pseudo-elements are stored behind a combinator of their own
+        if compound.is_empty() {
+            continue;
+        }
+        let mut empty = true;
+
+        for component in compound {
+            match component {
+                Component::NonTSPseudoClass(pseudo_class) if pseudo_class.is_focus() => continue,
+                component => component.to_css(dest)?,
+            }
+            empty = false;
+        }
+        if empty {
+            dest.write_char('*')?;
+        }
+        if let Some(combinator) = combinators.next() {
+            combinator.to_css(dest)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// the selectors of the list in the focus state with the specificity of the source selector
+pub fn focus_selectors(list: &SelectorList) -> Vec<(Selector, u32)> {
+    let mut selectors = Vec::new();
+
+    for selector in list.0.iter().filter(|selector| has_focus_state(selector)) {
+        let mut stripped = String::new();
+
+        if strip_focus_state(selector, &mut stripped).is_err() {
+            continue;
+        }
+        let mut input = cssparser::ParserInput::new(&stripped);
+        let mut parser = cssparser::Parser::new(&mut input);
+
+        let parsed = SelectorList::parse(&Parser, &mut parser).ok();
+
+        if let Some(focus) = parsed {
+            selectors.extend(focus.0.into_iter().map(|s| (s, selector.specificity())));
+        }
+    }
+
+    selectors
 }
 
 #[derive(Copy, Clone)]
@@ -189,9 +282,8 @@ impl<'a> selectors::Element for NodeRef<'a> {
         use accessibility_scraper::selector::NonTSPseudoClass::*;
 
         match *pseudo_class {
-            Active | Focus | Hover | Enabled | Disabled | Checked | Indeterminate | Visited => {
-                false
-            }
+            Active | Focus | FocusVisible | FocusWithin | Hover | Enabled | Disabled | Checked
+            | Indeterminate | Visited => false,
             AnyLink | Link => {
                 self.value().name.ns == ns!(html)
                     && matches!(
@@ -0,0 +1,333 @@
+use crate::style::errors::PropertyParseError;
+use cssparser::{Parser, Token};
+
+/// the timing function and animation keywords that are not names
+const ANIMATION_KEYWORDS: [&str; 16] = [
+    "ease",
+    "linear",
+    "ease-in",
+    "ease-out",
+    "ease-in-out",
+    "step-start",
+    "step-end",
+    "normal",
+    "reverse",
+    "alternate",
+    "alternate-reverse",
+    "forwards",
+    "backwards",
+    "both",
+    "running",
+    "paused",
+];
+
+/// the properties that move the element when transitioned
+const MOTION_PROPERTIES: [&str; 16] = [
+    "all",
+    "transform",
+    "translate",
+    "rotate",
+    "scale",
+    "top",
+    "left",
+    "bottom",
+    "right",
+    "inset",
+    "margin",
+    "margin-top",
+    "margin-left",
+    "margin-bottom",
+    "margin-right",
+    "background-position",
+];
+
+/// skip the arguments of a timing function
+fn skip_function<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<(), PropertyParseError<'i>> {
+    parser.parse_nested_block(|parser| {
+        while parser.next().is_ok() {}
+        Ok(())
+    })
+}
+
+/// <https://drafts.csswg.org/css-values/#time>
+///
+/// The longest time of the layers is kept in seconds.
+#[derive(Copy, Clone, Debug, PartialEq, SpecifiedAsComputed)]
+pub struct Time(pub f32);
+
+impl Time {
+    /// the time does not last
+    pub fn is_zero(self) -> bool {
+        self.0 <= 0.
+    }
+
+    /// the longer of the times
+    fn max(self, other: Self) -> Self {
+        Time(self.0.max(other.0))
+    }
+
+    /// parse a single time
+    fn parse_time<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        match parser.next()? {
+            Token::Dimension { value, unit, .. } if unit.eq_ignore_ascii_case("s") => {
+                Ok(Time(*value))
+            }
+            Token::Dimension { value, unit, .. } if unit.eq_ignore_ascii_case("ms") => {
+                Ok(Time(*value / 1000.))
+            }
+            Token::Number { value, .. } if *value == 0. => Ok(Time(0.)),
+            token => {
+                let t = token.clone();
+                Err(parser.new_unexpected_token_error(t))
+            }
+        }
+    }
+}
+
+impl super::Parse for Time {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let layers = parser.parse_comma_separated(Time::parse_time)?;
+
+        Ok(layers.into_iter().fold(Time(0.), Time::max))
+    }
+}
+
+/// <https://drafts.csswg.org/css-animations/#animation-name>
+///
+/// Only whether any layer names keyframes is kept.
+#[derive(Copy, Clone, Debug, PartialEq, SpecifiedAsComputed)]
+pub enum AnimationName {
+    None,
+    Named,
+}
+
+impl AnimationName {
+    /// parse the name of a single animation layer
+    fn parse_layer<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        match parser.next()? {
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("none") => Ok(AnimationName::None),
+            Token::Ident(_) | Token::QuotedString(_) => Ok(AnimationName::Named),
+            token => {
+                let t = token.clone();
+                Err(parser.new_unexpected_token_error(t))
+            }
+        }
+    }
+}
+
+impl super::Parse for AnimationName {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let layers = parser.parse_comma_separated(AnimationName::parse_layer)?;
+
+        Ok(if layers.contains(&AnimationName::Named) {
+            AnimationName::Named
+        } else {
+            AnimationName::None
+        })
+    }
+}
+
+/// <https://drafts.csswg.org/css-animations/#animation-iteration-count>
+///
+/// The largest count of the layers is kept.
+#[derive(Copy, Clone, Debug, PartialEq, SpecifiedAsComputed)]
+pub enum IterationCount {
+    Count(f32),
+    Infinite,
+}
+
+impl IterationCount {
+    /// the animation repeats forever
+    pub fn is_infinite(self) -> bool {
+        self == IterationCount::Infinite
+    }
+
+    /// the larger of the counts
+    fn max(self, other: Self) -> Self {
+        match (self, other) {
+            (IterationCount::Count(a), IterationCount::Count(b)) => IterationCount::Count(a.max(b)),
+            _ => IterationCount::Infinite,
+        }
+    }
+
+    /// parse the count of a single animation layer
+    fn parse_layer<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        match parser.next()? {
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("infinite") => {
+                Ok(IterationCount::Infinite)
+            }
+            Token::Number { value, .. } if *value >= 0. => Ok(IterationCount::Count(*value)),
+            token => {
+                let t = token.clone();
+                Err(parser.new_unexpected_token_error(t))
+            }
+        }
+    }
+}
+
+impl super::Parse for IterationCount {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let layers = parser.parse_comma_separated(IterationCount::parse_layer)?;
+
+        Ok(layers
+            .into_iter()
+            .fold(IterationCount::Count(0.), IterationCount::max))
+    }
+}
+
+/// <https://drafts.csswg.org/css-transitions/#transition-property-property>
+///
+/// Only whether any of the properties moves the element is kept.
+#[derive(Copy, Clone, Debug, PartialEq, SpecifiedAsComputed)]
+pub enum TransitionProperty {
+    None,
+    Static,
+    Motion,
+}
+
+impl TransitionProperty {
+    /// the transition moves the element
+    pub fn is_motion(self) -> bool {
+        self == TransitionProperty::Motion
+    }
+
+    /// the transition of the property
+    fn from_property(name: &str) -> Self {
+        if name.eq_ignore_ascii_case("none") {
+            TransitionProperty::None
+        } else if MOTION_PROPERTIES.contains(&&*name.to_ascii_lowercase()) {
+            TransitionProperty::Motion
+        } else {
+            TransitionProperty::Static
+        }
+    }
+
+    /// the transition of either property
+    fn union(self, other: Self) -> Self {
+        match (self, other) {
+            (TransitionProperty::Motion, _) | (_, TransitionProperty::Motion) => {
+                TransitionProperty::Motion
+            }
+            (TransitionProperty::Static, _) | (_, TransitionProperty::Static) => {
+                TransitionProperty::Static
+            }
+            _ => TransitionProperty::None,
+        }
+    }
+}
+
+impl super::Parse for TransitionProperty {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let layers = parser.parse_comma_separated(|parser| {
+            let name = parser.expect_ident()?;
+            Ok(TransitionProperty::from_property(name))
+        })?;
+
+        Ok(layers
+            .into_iter()
+            .fold(TransitionProperty::None, TransitionProperty::union))
+    }
+}
+
+/// <https://drafts.csswg.org/css-animations/#animation>
+///
+/// Only the names, durations and iteration counts are kept.
+pub struct Animation {
+    pub name: AnimationName,
+    pub duration: Time,
+    pub iteration_count: IterationCount,
+}
+
+impl super::Parse for Animation {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let layers = parser.parse_comma_separated(|parser| {
+            let mut name = AnimationName::None;
+            let mut duration = None;
+            let mut iteration_count = IterationCount::Count(1.);
+
+            while !parser.is_exhausted() {
+                if let Ok(time) = parser.r#try(Time::parse_time) {
+                    // the second time is the delay
+                    duration.get_or_insert(time);
+                } else if let Ok(count) = parser.r#try(IterationCount::parse_layer) {
+                    iteration_count = count;
+                } else {
+                    match parser.next()?.clone() {
+                        Token::Function(_) => skip_function(parser)?,
+                        Token::Ident(ident)
+                            if ANIMATION_KEYWORDS.contains(&&*ident.to_ascii_lowercase())
+                                || ident.eq_ignore_ascii_case("none") => {}
+                        Token::Ident(_) | Token::QuotedString(_) => name = AnimationName::Named,
+                        token => return Err(parser.new_unexpected_token_error(token)),
+                    }
+                }
+            }
+
+            Ok((name, duration.unwrap_or(Time(0.)), iteration_count))
+        })?;
+
+        let mut animation = Animation {
+            name: AnimationName::None,
+            duration: Time(0.),
+            iteration_count: IterationCount::Count(0.),
+        };
+
+        for (name, duration, iteration_count) in layers {
+            if name == AnimationName::Named {
+                animation.name = name;
+            }
+            animation.duration = animation.duration.max(duration);
+            animation.iteration_count = animation.iteration_count.max(iteration_count);
+        }
+
+        Ok(animation)
+    }
+}
+
+/// <https://drafts.csswg.org/css-transitions/#transition-shorthand-property>
+///
+/// Only the properties and durations are kept.
+pub struct Transition {
+    pub property: TransitionProperty,
+    pub duration: Time,
+}
+
+impl super::Parse for Transition {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let layers = parser.parse_comma_separated(|parser| {
+            // all properties transition when none is named
+            let mut property = TransitionProperty::Motion;
+            let mut duration = None;
+
+            while !parser.is_exhausted() {
+                if let Ok(time) = parser.r#try(Time::parse_time) {
+                    // the second time is the delay
+                    duration.get_or_insert(time);
+                } else {
+                    match parser.next()?.clone() {
+                        Token::Function(_) => skip_function(parser)?,
+                        Token::Ident(ident)
+                            if ANIMATION_KEYWORDS.contains(&&*ident.to_ascii_lowercase()) => {}
+                        Token::Ident(ident) => property = TransitionProperty::from_property(&ident),
+                        token => return Err(parser.new_unexpected_token_error(token)),
+                    }
+                }
+            }
+
+            Ok((property, duration.unwrap_or(Time(0.))))
+        })?;
+
+        let mut property = TransitionProperty::None;
+        let mut duration = Time(0.);
+
+        for (layer_property, layer_duration) in layers {
+            // the layers that do not last do not transition
+            if !layer_duration.is_zero() {
+                property = property.union(layer_property);
+            }
+            duration = duration.max(layer_duration);
+        }
+
+        Ok(Transition { property, duration })
+    }
+}
@@ -1,14 +1,109 @@
 use crate::style::errors::PropertyParseError;
-use cssparser::{Color, Parser};
+use cssparser::{Color, Parser, Token};
 
 pub struct Background {
     pub color: Option<Color>,
+    pub image: Option<BackgroundImage>,
 }
 
 impl super::Parse for Background {
     fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
-        Ok(Background {
-            color: Some(Color::parse(parser)?),
+        let mut color = None;
+        let mut image = None;
+
+        while !parser.is_exhausted() {
+            if let Ok(value) = parser.r#try(Color::parse) {
+                color = Some(value);
+            } else if let Ok(value) = parser.r#try(BackgroundImage::parse_layer) {
+                // an image in any of the layers is kept
+                if image != Some(BackgroundImage::Image) {
+                    image = Some(value)
+                }
+            } else {
+                // positions, sizes, repeats and layer separators are not kept
+                parser.next()?;
+            }
+        }
+
+        if color.is_none() && image.is_none() {
+            return Err(parser.new_error_for_next_token());
+        }
+
+        Ok(Background { color, image })
+    }
+}
+
+/// <https://drafts.csswg.org/css-backgrounds/#background-image>
+///
+/// Only the presence of an image or gradient is kept.
+#[derive(Copy, Clone, Debug, PartialEq, SpecifiedAsComputed)]
+pub enum BackgroundImage {
+    None,
+    Image,
+}
+
+impl BackgroundImage {
+    /// parse the image of a single background layer
+    fn parse_layer<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        match parser.next()?.clone() {
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("none") => Ok(BackgroundImage::None),
+            Token::UnquotedUrl(_) => Ok(BackgroundImage::Image),
+            Token::Function(name)
+                if name.eq_ignore_ascii_case("url")
+                    || name.eq_ignore_ascii_case("image")
+                    || name.eq_ignore_ascii_case("image-set")
+                    || name.eq_ignore_ascii_case("-webkit-image-set")
+                    || name.eq_ignore_ascii_case("cross-fade")
+                    || name.to_ascii_lowercase().ends_with("gradient") =>
+            {
+                parser.parse_nested_block(|parser| {
+                    while parser.next().is_ok() {}
+                    Ok(())
+                })?;
+                Ok(BackgroundImage::Image)
+            }
+            token => Err(parser.new_unexpected_token_error(token)),
+        }
+    }
+}
+
+impl super::Parse for BackgroundImage {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let layers = parser.parse_comma_separated(BackgroundImage::parse_layer)?;
+
+        Ok(if layers.contains(&BackgroundImage::Image) {
+            BackgroundImage::Image
+        } else {
+            BackgroundImage::None
         })
     }
 }
+
+/// <https://drafts.csswg.org/css-backgrounds/#box-shadow>
+///
+/// Only the presence of a shadow is kept.
+#[derive(Copy, Clone, PartialEq, SpecifiedAsComputed)]
+pub enum BoxShadow {
+    None,
+    Shadow,
+}
+
+impl super::Parse for BoxShadow {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        if parser
+            .r#try(|parser| parser.expect_ident_matching("none"))
+            .is_ok()
+        {
+            return Ok(BoxShadow::None);
+        }
+        let mut any = false;
+        while parser.next().is_ok() {
+            any = true
+        }
+        if any {
+            Ok(BoxShadow::Shadow)
+        } else {
+            Err(parser.new_error_for_next_token())
+        }
+    }
+}
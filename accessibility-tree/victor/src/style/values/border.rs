@@ -6,7 +6,22 @@ use cssparser::{Color, Parser};
 #[derive(Copy, Clone, Parse, SpecifiedAsComputed)]
 pub enum LineStyle {
     None,
+    Hidden,
+    Dotted,
+    Dashed,
     Solid,
+    Double,
+    Groove,
+    Ridge,
+    Inset,
+    Outset,
+}
+
+/// <https://drafts.csswg.org/css-ui/#outline-style>
+#[derive(Copy, Clone, Parse, SpecifiedAsComputed)]
+pub enum OutlineStyle {
+    Auto,
+    Style(LineStyle),
 }
 
 #[derive(Parse)]
@@ -27,7 +42,7 @@ impl LineWidth {
     pub const MEDIUM: Self = LineWidth(LengthOrPercentage::Length(Length { px: 3. }));
 
     pub fn fixup(&mut self, style: LineStyle) {
-        if let LineStyle::None = style {
+        if let LineStyle::None | LineStyle::Hidden = style {
             self.0 = LengthOrPercentage::Length(Length::zero())
         }
     }
@@ -49,7 +64,7 @@ impl super::Parse for SpecifiedLineWidth {
 
 macro_rules! parse_one_or_more {
     ($type: ty { $( $field: ident, )+ }) => {
-        impl crate::style::values::Parse for BorderSide {
+        impl crate::style::values::Parse for $type {
             fn parse<'i, 't>(parser: &mut Parser<'i, 't>)
                 -> Result<Self, PropertyParseError<'i>>
             {
@@ -89,3 +104,56 @@ pub struct BorderSide {
     pub color: Option<Color>,
     pub width: Option<SpecifiedLineWidth>,
 }
+
+/// the same side is set on all four sides
+pub struct Border {
+    pub top_style: Option<LineStyle>,
+    pub left_style: Option<LineStyle>,
+    pub bottom_style: Option<LineStyle>,
+    pub right_style: Option<LineStyle>,
+    pub top_color: Option<Color>,
+    pub left_color: Option<Color>,
+    pub bottom_color: Option<Color>,
+    pub right_color: Option<Color>,
+    pub top_width: Option<SpecifiedLineWidth>,
+    pub left_width: Option<SpecifiedLineWidth>,
+    pub bottom_width: Option<SpecifiedLineWidth>,
+    pub right_width: Option<SpecifiedLineWidth>,
+}
+
+impl crate::style::values::Parse for Border {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let BorderSide {
+            style,
+            color,
+            width,
+        } = BorderSide::parse(parser)?;
+        Ok(Border {
+            top_style: style,
+            left_style: style,
+            bottom_style: style,
+            right_style: style,
+            top_color: color,
+            left_color: color,
+            bottom_color: color,
+            right_color: color,
+            top_width: width.clone(),
+            left_width: width.clone(),
+            bottom_width: width.clone(),
+            right_width: width,
+        })
+    }
+}
+
+parse_one_or_more!(Outline {
+    style,
+    color,
+    width,
+});
+
+#[derive(Default)]
+pub struct Outline {
+    pub style: Option<OutlineStyle>,
+    pub color: Option<Color>,
+    pub width: Option<SpecifiedLineWidth>,
+}
@@ -140,3 +140,59 @@ impl Position {
         self == Position::Absolute
     }
 }
+
+/// <https://drafts.csswg.org/css-display-3/#visibility>
+#[derive(Copy, Clone, Debug, Eq, Parse, PartialEq, SpecifiedAsComputed)]
+pub enum Visibility {
+    Visible,
+    Hidden,
+    Collapse,
+}
+
+impl Visibility {
+    /// the boxes are invisible
+    pub fn is_hidden(self) -> bool {
+        self != Visibility::Visible
+    }
+}
+
+/// <https://drafts.csswg.org/css-overflow-3/#propdef-overflow>
+///
+/// Both axes are kept as the value that clips the most.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, SpecifiedAsComputed)]
+pub enum Overflow {
+    Visible,
+    Hidden,
+    Clip,
+    Scroll,
+    Auto,
+}
+
+impl Overflow {
+    /// the content outside of the box is not painted
+    pub fn clips(self) -> bool {
+        self != Overflow::Visible
+    }
+
+    fn parse_keyword<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let ident = parser.expect_ident()?.clone();
+        match_ignore_ascii_case!(&ident,
+            "visible" => Ok(Overflow::Visible),
+            "hidden" => Ok(Overflow::Hidden),
+            "clip" => Ok(Overflow::Clip),
+            "scroll" => Ok(Overflow::Scroll),
+            "auto" => Ok(Overflow::Auto),
+            _ => Err(parser.new_unexpected_token_error(cssparser::Token::Ident(ident))),
+        )
+    }
+}
+
+impl super::Parse for Overflow {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let x = Overflow::parse_keyword(parser)?;
+        Ok(match parser.r#try(Overflow::parse_keyword) {
+            Ok(y) if !x.clips() => y,
+            _ => x,
+        })
+    }
+}
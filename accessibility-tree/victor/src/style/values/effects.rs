@@ -0,0 +1,122 @@
+use super::length::{Length, SpecifiedLength};
+use crate::style::errors::PropertyParseError;
+use cssparser::{Parser, Token};
+
+/// <https://drafts.csswg.org/css-color/#transparency>
+#[derive(Copy, Clone, Debug, PartialEq, SpecifiedAsComputed)]
+pub struct Opacity(pub f32);
+
+impl Opacity {
+    /// nothing of the element is painted
+    pub fn is_transparent(self) -> bool {
+        self.0 <= 0.
+    }
+}
+
+impl super::Parse for Opacity {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let value = match parser.next()? {
+            Token::Number { value, .. } => *value,
+            Token::Percentage { unit_value, .. } => *unit_value,
+            token => {
+                let t = token.clone();
+                return Err(parser.new_unexpected_token_error(t));
+            }
+        };
+        Ok(Opacity(value.clamp(0., 1.)))
+    }
+}
+
+/// <https://drafts.fxtf.org/css-masking/#clip-property>
+///
+/// Only whether the rectangle leaves any area visible is kept.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, SpecifiedAsComputed)]
+pub enum Clip {
+    Auto,
+    Rect,
+    Hidden,
+}
+
+impl super::Parse for Clip {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        if parser
+            .r#try(|parser| parser.expect_ident_matching("auto"))
+            .is_ok()
+        {
+            return Ok(Clip::Auto);
+        }
+        parser.expect_function_matching("rect")?;
+        let edges = parser.parse_nested_block(|parser| {
+            let mut edges = Vec::new();
+            while !parser.is_exhausted() {
+                let _ = parser.r#try(|parser| parser.expect_comma());
+                if parser
+                    .r#try(|parser| parser.expect_ident_matching("auto"))
+                    .is_ok()
+                {
+                    edges.push(None)
+                } else {
+                    edges.push(match super::Parse::parse(parser)? {
+                        SpecifiedLength::Absolute(Length { px }) => Some(px),
+                        SpecifiedLength::Em(_) => None,
+                    })
+                }
+            }
+            Ok(edges)
+        })?;
+        // rect(<top>, <right>, <bottom>, <left>) with the edges offset from the top left corner
+        Ok(match edges[..] {
+            [Some(top), Some(right), Some(bottom), Some(left)]
+                if bottom <= top || right <= left =>
+            {
+                Clip::Hidden
+            }
+            _ => Clip::Rect,
+        })
+    }
+}
+
+/// <https://drafts.fxtf.org/css-masking/#the-clip-path>
+///
+/// Only whether the shape leaves any area visible is kept.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, SpecifiedAsComputed)]
+pub enum ClipPath {
+    None,
+    Shape,
+    Hidden,
+}
+
+impl super::Parse for ClipPath {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let name = match parser.next()?.clone() {
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("none") => return Ok(ClipPath::None),
+            Token::Function(name) => name,
+            _ => {
+                // urls and geometry boxes
+                while parser.next().is_ok() {}
+                return Ok(ClipPath::Shape);
+            }
+        };
+        let hidden = parser.parse_nested_block(|parser| {
+            // the first argument collapses the shape: inset(50%) or circle(0)
+            let hidden = match parser.next()? {
+                Token::Percentage { unit_value, .. } => {
+                    name.eq_ignore_ascii_case("inset") && *unit_value >= 0.5
+                        || name.eq_ignore_ascii_case("circle") && *unit_value <= 0.
+                }
+                Token::Number { value, .. } | Token::Dimension { value, .. } => {
+                    name.eq_ignore_ascii_case("circle") && *value <= 0.
+                }
+                _ => false,
+            };
+            while parser.next().is_ok() {}
+            Ok(hidden)
+        })?;
+        while parser.next().is_ok() {}
+        Ok(if hidden {
+            ClipPath::Hidden
+        } else {
+            ClipPath::Shape
+        })
+    }
+}
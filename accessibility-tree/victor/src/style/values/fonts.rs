@@ -1,4 +1,9 @@
-use super::{EarlyCascadeContext, EarlyFromSpecified, Length, SpecifiedLength, SpecifiedValue};
+use super::{
+    CascadeContext, EarlyCascadeContext, EarlyFromSpecified, FromSpecified, Length, Parse,
+    SpecifiedLength, SpecifiedValue,
+};
+use crate::style::errors::PropertyParseError;
+use cssparser::{Parser, Token};
 
 #[derive(Copy, Clone)]
 pub struct FontSize(pub Length);
@@ -38,3 +43,82 @@ impl std::ops::Div<FontSize> for Length {
         Em::new(self.px / other.0.px)
     }
 }
+
+/// <https://drafts.csswg.org/css-fonts-4/#font-weight-prop>
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct FontWeight(pub f32);
+
+impl FontWeight {
+    pub const NORMAL: Self = FontWeight(400.);
+    pub const BOLD: Self = FontWeight(700.);
+
+    /// the weight is rendered bold
+    pub fn is_bold(&self) -> bool {
+        self.0 >= Self::BOLD.0
+    }
+
+    /// <https://drafts.csswg.org/css-fonts-4/#relative-weights>
+    fn bolder(self) -> Self {
+        FontWeight(match self.0 {
+            w if w < 350. => 400.,
+            w if w < 550. => 700.,
+            w if w < 900. => 900.,
+            w => w,
+        })
+    }
+
+    /// <https://drafts.csswg.org/css-fonts-4/#relative-weights>
+    fn lighter(self) -> Self {
+        FontWeight(match self.0 {
+            w if w < 100. => w,
+            w if w < 550. => 100.,
+            w if w < 750. => 400.,
+            _ => 700.,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub enum SpecifiedFontWeight {
+    Absolute(FontWeight),
+    Bolder,
+    Lighter,
+}
+
+impl Parse for SpecifiedFontWeight {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        match parser.next()? {
+            Token::Number { value, .. } if (1. ..=1000.).contains(value) => {
+                Ok(SpecifiedFontWeight::Absolute(FontWeight(*value)))
+            }
+            Token::Ident(ident) => match_ignore_ascii_case! { ident,
+                "normal" => Ok(SpecifiedFontWeight::Absolute(FontWeight::NORMAL)),
+                "bold" => Ok(SpecifiedFontWeight::Absolute(FontWeight::BOLD)),
+                "bolder" => Ok(SpecifiedFontWeight::Bolder),
+                "lighter" => Ok(SpecifiedFontWeight::Lighter),
+                _ => {
+                    let token = Token::Ident(ident.clone());
+                    Err(parser.new_unexpected_token_error(token))
+                }
+            },
+            token => {
+                let token = token.clone();
+                Err(parser.new_unexpected_token_error(token))
+            }
+        }
+    }
+}
+
+impl SpecifiedValue for FontWeight {
+    type SpecifiedValue = SpecifiedFontWeight;
+}
+
+impl FromSpecified for FontWeight {
+    fn from_specified(s: &SpecifiedFontWeight, context: &CascadeContext) -> Self {
+        match s {
+            SpecifiedFontWeight::Absolute(weight) => *weight,
+            SpecifiedFontWeight::Bolder => context.inherited.font.font_weight.bolder(),
+            SpecifiedFontWeight::Lighter => context.inherited.font.font_weight.lighter(),
+        }
+    }
+}
@@ -71,6 +71,7 @@ impl Parse for SpecifiedLength {
         match parser.next()? {
             Token::Dimension { value, unit, .. } => match_ignore_ascii_case!(unit,
                 "px" => Ok(SpecifiedLength::Absolute(Length { px: *value })),
+                "pt" => Ok(SpecifiedLength::Absolute(Length { px: *value * 4. / 3. })),
                 "em" => Ok(SpecifiedLength::Em(*value)),
                 _ => {
                     let u = unit.clone();
@@ -3,16 +3,19 @@ use crate::style::properties::ComputedValues;
 use crate::style::properties::{ComputedValuesForEarlyCascade, ComputedValuesForLateCascade};
 use cssparser::Parser;
 
+mod animation;
 mod background;
 mod border;
 mod box_;
 mod color;
+mod effects;
 mod fonts;
 mod generic;
 mod length;
+mod text;
 mod writing_modes;
 
-pub use self::{background::*, generic::*};
+pub use self::{animation::*, background::*, effects::*, generic::*, text::*};
 pub use self::{border::*, box_::*, color::*, fonts::*, length::*, writing_modes::*};
 
 pub trait Parse: Sized {
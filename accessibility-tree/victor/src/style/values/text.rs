@@ -0,0 +1,93 @@
+use crate::style::errors::PropertyParseError;
+use cssparser::{Parser, Token};
+
+/// <https://drafts.csswg.org/css-text-decor/#text-decoration-line-property>
+#[derive(Copy, Clone, Debug, PartialEq, SpecifiedAsComputed)]
+pub struct TextDecorationLine {
+    pub underline: bool,
+    pub overline: bool,
+    pub line_through: bool,
+}
+
+impl TextDecorationLine {
+    pub const NONE: Self = TextDecorationLine {
+        underline: false,
+        overline: false,
+        line_through: false,
+    };
+
+    /// no line is drawn
+    pub fn is_none(&self) -> bool {
+        *self == Self::NONE
+    }
+
+    /// the lines drawn by either decoration
+    pub fn union(self, other: Self) -> Self {
+        TextDecorationLine {
+            underline: self.underline || other.underline,
+            overline: self.overline || other.overline,
+            line_through: self.line_through || other.line_through,
+        }
+    }
+
+    /// parse a single line keyword into the lines
+    fn parse_line<'i, 't>(
+        &mut self,
+        parser: &mut Parser<'i, 't>,
+    ) -> Result<(), PropertyParseError<'i>> {
+        let ident = parser.expect_ident()?.clone();
+
+        match &*ident.to_ascii_lowercase() {
+            "underline" if !self.underline => self.underline = true,
+            "overline" if !self.overline => self.overline = true,
+            "line-through" if !self.line_through => self.line_through = true,
+            // blink is valid but not rendered
+            "blink" => {}
+            _ => return Err(parser.new_unexpected_token_error(Token::Ident(ident))),
+        }
+        Ok(())
+    }
+}
+
+impl super::Parse for TextDecorationLine {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        if parser
+            .r#try(|parser| parser.expect_ident_matching("none"))
+            .is_ok()
+        {
+            return Ok(TextDecorationLine::NONE);
+        }
+        let mut line = TextDecorationLine::NONE;
+        line.parse_line(parser)?;
+        while !parser.is_exhausted() {
+            line.parse_line(parser)?;
+        }
+        Ok(line)
+    }
+}
+
+/// <https://drafts.csswg.org/css-text-decor/#text-decoration-property>
+///
+/// Only the lines are kept.
+pub struct TextDecoration {
+    pub line: TextDecorationLine,
+}
+
+impl super::Parse for TextDecoration {
+    fn parse<'i, 't>(parser: &mut Parser<'i, 't>) -> Result<Self, PropertyParseError<'i>> {
+        let mut line = TextDecorationLine::NONE;
+
+        while !parser.is_exhausted() {
+            if parser
+                .r#try(|parser| parser.expect_ident_matching("none"))
+                .is_err()
+                && parser.r#try(|parser| line.parse_line(parser)).is_err()
+            {
+                // styles, colors and thickness are not kept
+                parser.next()?;
+            }
+        }
+
+        Ok(TextDecoration { line })
+    }
+}